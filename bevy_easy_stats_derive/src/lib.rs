@@ -0,0 +1,94 @@
+//! Derive macros for `bevy_easy_stats`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Derives `StatIdentifier` for unit structs and fieldless enums.
+///
+/// Structs use the type name as their identifier and enum variants use `Type::Variant`. Either can be
+/// overridden with `#[stat(id = "custom string")]`.
+#[proc_macro_derive(StatIdentifier, attributes(stat))]
+pub fn derive_stat_identifier(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_stat_identifier(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_stat_identifier(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            if !matches!(data.fields, Fields::Unit) {
+                return Err(Error::new_spanned(
+                    &data.fields,
+                    "StatIdentifier can only be derived for unit structs",
+                ));
+            }
+            let id = stat_id(&input.attrs)?.unwrap_or_else(|| ident.to_string());
+            quote! { #id }
+        }
+        Data::Enum(data) => {
+            if let Some(attr) = input.attrs.iter().find(|attr| attr.path().is_ident("stat")) {
+                return Err(Error::new_spanned(
+                    attr,
+                    "#[stat(id = \"...\")] must be placed on the enum variants",
+                ));
+            }
+            let mut arms = Vec::with_capacity(data.variants.len());
+            for variant in data.variants.iter() {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error::new_spanned(
+                        &variant.fields,
+                        "StatIdentifier can only be derived for fieldless enums",
+                    ));
+                }
+                let variant_ident = &variant.ident;
+                let id = stat_id(&variant.attrs)?
+                    .unwrap_or_else(|| format!("{}::{}", ident, variant_ident));
+                arms.push(quote! { Self::#variant_ident => #id, });
+            }
+            quote! {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "StatIdentifier cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::bevy_easy_stats::StatIdentifier for #ident #ty_generics #where_clause {
+            fn identifier(&self) -> &'static str {
+                #body
+            }
+        }
+    })
+}
+
+/// Reads the id out of a `#[stat(id = "...")]` attribute if one is present
+fn stat_id(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let mut id = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("stat")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("id") {
+                let value: LitStr = meta.value()?.parse()?;
+                id = Some(value.value());
+                Ok(())
+            } else {
+                Err(meta.error("unsupported stat attribute, expected `id`"))
+            }
+        })?;
+    }
+    Ok(id)
+}
@@ -0,0 +1,120 @@
+//! Alternate ids that resolve to the same stat, for keeping old ids working after a rename.
+
+use std::borrow::Cow;
+
+use crate::{StatIdentifier, Stats};
+
+impl Stats {
+    /// Makes the given alias resolve to the canonical str id for every read and write through this collection.
+    ///
+    /// Aliases don't store any data. Removing the canonical stat through either id leaves the alias in place so it
+    /// reads `None` until the stat is created again. A stat stored under the alias itself is no longer reachable
+    /// through the str id methods, use [`Stats::rename_stat_manual`] to move it first. Returns false without adding
+    /// the alias if it would resolve to itself.
+    ///
+    /// Aliases are not serialized, add them again after loading
+    pub fn add_alias_manual(&mut self, alias: &str, canonical: &str) -> bool {
+        let canonical = self.resolve_alias(canonical).into_owned();
+        if canonical == alias {
+            return false;
+        }
+        for target in self.aliases.values_mut() {
+            if target == alias {
+                *target = canonical.clone();
+            }
+        }
+        self.aliases.insert(alias.to_string(), canonical);
+        true
+    }
+
+    /// Makes the given alias resolve to the requested [`StatIdentifier`], see [`Stats::add_alias_manual`]
+    pub fn add_alias(&mut self, alias: &str, canonical: &impl StatIdentifier) -> bool {
+        self.add_alias_manual(alias, canonical.identifier())
+    }
+
+    /// Removes the given alias, returning the id it resolved to
+    pub fn remove_alias(&mut self, alias: &str) -> Option<String> {
+        self.aliases.remove(alias)
+    }
+
+    /// Returns an iterator over every alias and the id it resolves to
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.aliases
+            .iter()
+            .map(|(alias, canonical)| (alias.as_str(), canonical.as_str()))
+    }
+
+    /// Returns the id the given str id resolves to, itself if it isn't an alias
+    pub(crate) fn resolve_alias<'a>(&self, stat_id: &'a str) -> Cow<'a, str> {
+        match self.aliases.get(stat_id) {
+            Some(canonical) => Cow::Owned(canonical.clone()),
+            None => Cow::Borrowed(stat_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MergeMode;
+
+    use super::*;
+
+    pub struct Points;
+
+    impl StatIdentifier for Points {
+        fn identifier(&self) -> &'static str {
+            "points"
+        }
+    }
+
+    #[test]
+    fn stat_aliases() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Points, Box::new(10u64));
+        assert!(stats.add_alias("score", &Points));
+        assert!(stats.add_alias_manual("old_score", "score"));
+        assert!(!stats.add_alias_manual("points", "old_score"));
+
+        stats.add_to_stat_manual("old_score", Box::new(5u64));
+        assert_eq!(
+            stats
+                .get_stat_manual("score")
+                .unwrap()
+                .downcast_ref::<u64>(),
+            Some(&15)
+        );
+        assert_eq!(stats.get_stat_downcast::<u64>(&Points), Some(&15));
+        assert_eq!(stats.len(), 1);
+
+        stats.remove_stat(&Points);
+        assert!(!stats.contains_stat_manual("score"));
+        stats.set_stat_manual("score", Box::new(1u64));
+        assert_eq!(stats.get_stat_downcast::<u64>(&Points), Some(&1));
+
+        assert_eq!(stats.remove_alias("score"), Some("points".to_string()));
+        assert!(!stats.contains_stat_manual("score"));
+    }
+
+    #[test]
+    fn aliases_in_rename_and_merge() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Points, Box::new(10u64));
+        stats.add_alias("score", &Points);
+
+        let mut other = Stats::new();
+        other.set_stat_manual("score", Box::new(99u64));
+        stats.merge_with(other, MergeMode::KeepExisting);
+        assert_eq!(stats.get_stat_downcast::<u64>(&Points), Some(&10));
+        assert_eq!(stats.len(), 1);
+
+        assert!(stats.rename_stat_manual("score", "total", MergeMode::Overwrite));
+        assert!(!stats.contains_stat(&Points));
+        assert_eq!(
+            stats
+                .get_stat_manual("total")
+                .unwrap()
+                .downcast_ref::<u64>(),
+            Some(&10)
+        );
+    }
+}
@@ -0,0 +1,194 @@
+//! Approximate equality for float stats so rounding drift isn't reported as a change.
+
+use std::cmp::Ordering;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// A float type that can be compared with a tolerance
+pub(crate) trait Float: Copy + PartialOrd {
+    const EPSILON: Self;
+
+    fn abs_diff(self, other: Self) -> Self;
+
+    fn magnitude(self, other: Self) -> Self;
+
+    fn scale(self, by: Self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($ty:ident),*) => {
+        $(
+            impl Float for $ty {
+                const EPSILON: Self = $ty::EPSILON;
+
+                fn abs_diff(self, other: Self) -> Self {
+                    (self - other).abs()
+                }
+
+                fn magnitude(self, other: Self) -> Self {
+                    self.abs().max(other.abs()).max(1.0)
+                }
+
+                fn scale(self, by: Self) -> Self {
+                    self * by
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f64, f32);
+
+/// The tolerance the built in float implementations use, the type's epsilon scaled by the larger magnitude of the two
+/// values (at least 1)
+pub(crate) fn default_tolerance<T: Float>(a: T, b: T) -> T {
+    T::EPSILON.scale(a.magnitude(b))
+}
+
+/// Returns true if the two values are within the given tolerance of each other
+pub(crate) fn approx_eq<T: Float>(a: T, b: T, tolerance: T) -> bool {
+    a == b || a.abs_diff(b) <= tolerance
+}
+
+/// A float stat that treats values within a fixed tolerance as equal in [`StatData::eq_dyn`] and
+/// [`StatData::compare`].
+///
+/// Plain `f32` and `f64` stats only absorb drift of around their epsilon, use this when accumulated rounding is larger
+/// than that, such as a stat that sums many small values. Adding or subtracting either another [`ApproxFloat`] or the
+/// plain float type is supported and ignores `NaN` and infinite values the same as the plain float, resetting keeps
+/// the tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct ApproxFloat<T> {
+    value: T,
+    tolerance: T,
+}
+
+impl<T: Copy> ApproxFloat<T> {
+    /// Creates a new [`ApproxFloat`] where values at most `tolerance` apart are equal
+    pub fn new(value: T, tolerance: T) -> Self {
+        Self { value, tolerance }
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Sets the current value
+    pub fn set(&mut self, value: T) {
+        self.value = value;
+    }
+
+    /// Returns the tolerance used when comparing
+    pub fn tolerance(&self) -> T {
+        self.tolerance
+    }
+
+    /// Sets the tolerance used when comparing
+    pub fn set_tolerance(&mut self, tolerance: T) {
+        self.tolerance = tolerance;
+    }
+}
+
+macro_rules! impl_approx_float {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for ApproxFloat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(ApproxFloat::new(0.0 as $ty, self.tolerance))
+                }
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    if let Some(other) = other.downcast_ref::<ApproxFloat<$ty>>() {
+                        StatData::add(&mut self.value, Box::new(other.value));
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        StatData::add(&mut self.value, Box::new(*other));
+                    }
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    if let Some(other) = other.downcast_ref::<ApproxFloat<$ty>>() {
+                        StatData::sub(&mut self.value, Box::new(other.value));
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        StatData::sub(&mut self.value, Box::new(*other));
+                    }
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    StatData::apply_percent(&mut self.value, percent);
+                }
+
+                fn compare(&self, other: &dyn StatData) -> Option<Ordering> {
+                    let other = if let Some(other) = other.downcast_ref::<ApproxFloat<$ty>>() {
+                        other.value
+                    } else {
+                        *other.downcast_ref::<$ty>()?
+                    };
+                    if approx_eq(self.value, other, self.tolerance) {
+                        return Some(Ordering::Equal);
+                    }
+                    self.value.partial_cmp(&other)
+                }
+
+                fn eq_dyn(&self, other: &dyn StatData) -> bool {
+                    self.compare(other) == Some(Ordering::Equal)
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_float!(
+    f64 => "ApproxFloat<f64>",
+    f32 => "ApproxFloat<f32>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::Distance, Stats};
+
+    use super::*;
+
+    #[test]
+    fn approx_float() {
+        assert!(StatData::eq_dyn(&(0.1f64 + 0.2), &0.3f64));
+        assert!(!StatData::eq_dyn(&0.3f64, &0.3000001f64));
+        assert_eq!(
+            StatData::compare(&1e10f32, &(1e10f32 + 1000.0)),
+            Some(Ordering::Equal)
+        );
+
+        let mut previous = Stats::new();
+        previous.set_stat(&Distance, Box::new(ApproxFloat::new(0.3f64, 1e-6)));
+        let mut current = previous.clone();
+        current.add_to_stat(&Distance, Box::new(0.0000001f64));
+        assert!(current.diff(&previous).is_empty());
+
+        current.add_to_stat(&Distance, Box::new(ApproxFloat::new(0.1f64, 1e-6)));
+        assert_eq!(current.diff(&previous).len(), 1);
+        assert_eq!(
+            current.get_stat(&Distance).unwrap().compare(&0.3f64),
+            Some(Ordering::Greater)
+        );
+
+        current.add_to_stat(&Distance, Box::new(f64::NAN));
+        current.sub_from_stat(&Distance, Box::new(ApproxFloat::new(f64::INFINITY, 1e-6)));
+        current.apply_percent(&Distance, f64::NAN);
+        assert_eq!(
+            current.get_stat(&Distance).unwrap().compare(&0.4f64),
+            Some(Ordering::Equal)
+        );
+
+        current.reset_stat(&Distance);
+        assert_eq!(
+            current.get_stat_downcast::<ApproxFloat<f64>>(&Distance),
+            Some(&ApproxFloat::new(0.0, 1e-6))
+        );
+    }
+}
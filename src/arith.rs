@@ -0,0 +1,197 @@
+//! A [`StatData`] wrapper for integer stats that lets each stat choose how overflow is handled.
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// How an [`ArithStat`] handles arithmetic overflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub enum OverflowMode {
+    /// Clamps at the numeric bounds of the type. This matches the plain integer implementations
+    #[default]
+    Saturating,
+    /// Wraps around at the numeric bounds of the type
+    Wrapping,
+    /// Leaves the stat unchanged when the operation would overflow and records it, see [`ArithStat::overflowed`]
+    Checked,
+}
+
+/// An integer stat that applies modifications using its [`OverflowMode`].
+///
+/// Adding or subtracting either another [`ArithStat`] or the plain integer type is supported, the mode of the stored
+/// stat is always the one that is used. Resetting the stat keeps the mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct ArithStat<T> {
+    value: T,
+    mode: OverflowMode,
+    overflowed: bool,
+}
+
+impl<T: Copy> ArithStat<T> {
+    /// Creates a new [`ArithStat`] using the given [`OverflowMode`]
+    pub fn new(value: T, mode: OverflowMode) -> Self {
+        Self {
+            value,
+            mode,
+            overflowed: false,
+        }
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the [`OverflowMode`] used by this stat
+    pub fn mode(&self) -> OverflowMode {
+        self.mode
+    }
+
+    /// Returns true if the last modification overflowed.
+    ///
+    /// Only ever set when using [`OverflowMode::Checked`]
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+}
+
+macro_rules! impl_arith_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for ArithStat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(ArithStat::new(0 as $ty, self.mode))
+                }
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<ArithStat<$ty>>() {
+                        other.value
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    self.overflowed = false;
+                    match self.mode {
+                        OverflowMode::Saturating => self.value = self.value.saturating_add(other),
+                        OverflowMode::Wrapping => self.value = self.value.wrapping_add(other),
+                        OverflowMode::Checked => match self.value.checked_add(other) {
+                            Some(value) => self.value = value,
+                            None => self.overflowed = true,
+                        },
+                    }
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<ArithStat<$ty>>() {
+                        other.value
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    self.overflowed = false;
+                    match self.mode {
+                        OverflowMode::Saturating => self.value = self.value.saturating_sub(other),
+                        OverflowMode::Wrapping => self.value = self.value.wrapping_sub(other),
+                        OverflowMode::Checked => match self.value.checked_sub(other) {
+                            Some(value) => self.value = value,
+                            None => self.overflowed = true,
+                        },
+                    }
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    let delta = (self.value as f64 * percent / 100.0).round();
+                    if delta >= 0.0 {
+                        StatData::add(self, Box::new(delta as $ty));
+                    } else {
+                        StatData::sub(self, Box::new((-delta) as $ty));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_arith_stat!(
+    u128 => "ArithStat<u128>",
+    u64 => "ArithStat<u64>",
+    u32 => "ArithStat<u32>",
+    u16 => "ArithStat<u16>",
+    u8 => "ArithStat<u8>",
+    i128 => "ArithStat<i128>",
+    i64 => "ArithStat<i64>",
+    i32 => "ArithStat<i32>",
+    i16 => "ArithStat<i16>",
+    i8 => "ArithStat<i8>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::TurnCounter, Stats};
+
+    use super::*;
+
+    fn value(stats: &Stats) -> ArithStat<u8> {
+        *stats
+            .get_stat_downcast::<ArithStat<u8>>(&TurnCounter)
+            .unwrap()
+    }
+
+    #[test]
+    fn overflow_modes() {
+        let mut stats = Stats::new();
+
+        stats.set_stat(
+            &TurnCounter,
+            Box::new(ArithStat::new(250u8, OverflowMode::default())),
+        );
+        stats.add_to_stat(&TurnCounter, Box::new(10u8));
+        assert_eq!(value(&stats).value(), 255);
+
+        stats.set_stat(
+            &TurnCounter,
+            Box::new(ArithStat::new(250u8, OverflowMode::Wrapping)),
+        );
+        stats.add_to_stat(&TurnCounter, Box::new(10u8));
+        assert_eq!(value(&stats).value(), 4);
+        stats.sub_from_stat(&TurnCounter, Box::new(5u8));
+        assert_eq!(value(&stats).value(), 255);
+
+        stats.set_stat(
+            &TurnCounter,
+            Box::new(ArithStat::new(250u8, OverflowMode::Checked)),
+        );
+        stats.add_to_stat(&TurnCounter, Box::new(10u8));
+        assert_eq!(value(&stats).value(), 250);
+        assert!(value(&stats).overflowed());
+        stats.add_to_stat(
+            &TurnCounter,
+            Box::new(ArithStat::new(5u8, OverflowMode::Wrapping)),
+        );
+        assert_eq!(value(&stats).value(), 255);
+        assert!(!value(&stats).overflowed());
+
+        stats.reset_stat(&TurnCounter);
+        assert_eq!(value(&stats), ArithStat::new(0u8, OverflowMode::Checked));
+    }
+
+    #[test]
+    fn plain_integers_saturate() {
+        let mut stats = Stats::new();
+        stats.set_stat(&TurnCounter, Box::new(120i8));
+        stats.add_to_stat(&TurnCounter, Box::new(10i8));
+        assert_eq!(stats.get_stat_downcast::<i8>(&TurnCounter), Some(&i8::MAX));
+
+        stats.set_stat(&TurnCounter, Box::new(-120i8));
+        stats.sub_from_stat(&TurnCounter, Box::new(10i8));
+        assert_eq!(stats.get_stat_downcast::<i8>(&TurnCounter), Some(&i8::MIN));
+    }
+}
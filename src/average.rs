@@ -0,0 +1,148 @@
+//! A [`StatData`] that smooths samples into an exponential moving average.
+
+use std::cmp::Ordering;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// An exponential moving average of every sample added to it, such as average FPS or damage per second.
+///
+/// - `add` feeds a new sample into the average rather than summing. Samples can be an `f64`, `f32`, or another
+///   [`MovingAverageStat`] whose current average is used as the sample. The first sample after creation or a reset
+///   becomes the average directly so it doesn't start biased towards zero. `NaN` and infinite samples are ignored
+/// - `sub` is a no-op, samples can't be removed from an exponential moving average
+/// - `default` is zero with the same alpha
+///
+/// Setting the stat replaces it, use [`MovingAverageStat::set`] through
+/// [`Stats::map_stat_downcast`](crate::Stats::map_stat_downcast) to restart the average at a value and keep the alpha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct MovingAverageStat {
+    average: f64,
+    alpha: f64,
+    samples: u64,
+}
+
+impl MovingAverageStat {
+    /// Creates a new [`MovingAverageStat`] at zero.
+    ///
+    /// `alpha` is the weight of each new sample and is clamped between 0 and 1, higher values react faster
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            average: 0.0,
+            alpha: alpha.clamp(0.0, 1.0),
+            samples: 0,
+        }
+    }
+
+    /// Returns the current average
+    pub fn average(&self) -> f64 {
+        self.average
+    }
+
+    /// Returns the weight of each new sample
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the number of samples added since creation or the last reset
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Feeds a new sample into the average. `NaN` and infinite samples are ignored
+    pub fn sample(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        if self.samples == 0 {
+            self.average = value;
+        } else {
+            let average = self.average + self.alpha * (value - self.average);
+            // The difference overflows for samples at opposite ends of the range, weighting each side separately
+            // can't
+            self.average = if average.is_finite() {
+                average
+            } else {
+                self.average * (1.0 - self.alpha) + value * self.alpha
+            };
+        }
+        self.samples = self.samples.saturating_add(1);
+    }
+
+    /// Restarts the average at the given value, counting it as a single sample
+    pub fn set(&mut self, value: f64) {
+        self.average = value;
+        self.samples = 1;
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for MovingAverageStat {
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(MovingAverageStat::new(self.alpha))
+    }
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<MovingAverageStat>() {
+            self.sample(other.average);
+        } else if let Some(other) = other.downcast_ref::<f64>() {
+            self.sample(*other);
+        } else if let Some(other) = other.downcast_ref::<f32>() {
+            self.sample(*other as f64);
+        }
+    }
+
+    fn sub(&mut self, _other: Box<dyn StatData>) {}
+
+    fn apply_percent(&mut self, percent: f64) {
+        StatData::apply_percent(&mut self.average, percent);
+    }
+
+    fn compare(&self, other: &dyn StatData) -> Option<Ordering> {
+        self.average
+            .partial_cmp(&other.downcast_ref::<MovingAverageStat>()?.average)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::Fps, Stats};
+
+    use super::*;
+
+    #[test]
+    fn moving_average_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Fps, Box::new(MovingAverageStat::new(0.5)));
+
+        stats.add_to_stat(&Fps, Box::new(60.0f64));
+        stats.add_to_stat(&Fps, Box::new(40.0f32));
+        stats.add_to_stat(&Fps, Box::new(30.0f64));
+        stats.sub_from_stat(&Fps, Box::new(1000.0f64));
+        stats.add_to_stat(&Fps, Box::new(f64::NAN));
+        stats.add_to_stat(&Fps, Box::new(f32::INFINITY));
+        let fps = stats.get_stat_downcast::<MovingAverageStat>(&Fps).unwrap();
+        assert_eq!(fps.average(), 40.0);
+        assert_eq!(fps.samples(), 3);
+
+        assert!(stats.map_stat_downcast::<MovingAverageStat>(&Fps, |fps| fps.set(10.0)));
+        stats.add_to_stat(&Fps, Box::new(20.0f64));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<MovingAverageStat>(&Fps)
+                .unwrap()
+                .average(),
+            15.0
+        );
+
+        stats.reset_stat(&Fps);
+        assert_eq!(
+            stats.get_stat_downcast::<MovingAverageStat>(&Fps),
+            Some(&MovingAverageStat::new(0.5))
+        );
+    }
+}
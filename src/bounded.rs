@@ -0,0 +1,227 @@
+//! A [`StatData`] wrapper that keeps a numeric stat within a min and max bound.
+
+use std::time::Duration;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// A numeric stat that is clamped between a `min` and `max` after every modification.
+///
+/// Adding or subtracting either another [`BoundedStat`] or the plain numeric type is supported, the bounds of the
+/// stored stat are always the ones that are kept. Setting the stat to the plain numeric type clamps the value and
+/// keeps the bounds, while setting another [`BoundedStat`] replaces them. Resetting the stat keeps the bounds and sets
+/// the value to `min`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct BoundedStat<T> {
+    value: T,
+    min: T,
+    max: T,
+}
+
+impl<T: PartialOrd + Copy> BoundedStat<T> {
+    /// Creates a new [`BoundedStat`], clamping the given value into the bounds
+    pub fn new(value: T, min: T, max: T) -> Self {
+        let mut stat = Self { value, min, max };
+        stat.clamp();
+        stat
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the lower bound
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the upper bound
+    pub fn max(&self) -> T {
+        self.max
+    }
+
+    /// Sets the value, clamping it into the bounds
+    pub fn set(&mut self, value: T) {
+        self.value = value;
+        self.clamp();
+    }
+
+    fn clamp(&mut self) {
+        if self.value < self.min {
+            self.value = self.min;
+        } else if self.value > self.max {
+            self.value = self.max;
+        }
+    }
+}
+
+/// Implements `add`, `sub`, `apply_percent`, and `set_from` of [`StatData`] for a wrapper that stores a numeric `$ty`
+/// in `$field`, calling `$after` on the wrapper once the value was modified.
+///
+/// Either another `$wrapper` or the plain numeric type can be added or subtracted, only the numeric value of the other
+/// stat is used and any other type is ignored. Only the plain numeric type is set in place with the wrapper's `set`
+macro_rules! wrapped_numeric_stat_ops {
+    ($wrapper:ty, $ty:ty, $field:ident, $after:ident) => {
+        fn add(&mut self, other: Box<dyn StatData>) {
+            let other = if let Some(other) = other.downcast_ref::<$wrapper>() {
+                other.$field
+            } else if let Some(other) = other.downcast_ref::<$ty>() {
+                *other
+            } else {
+                return;
+            };
+            StatData::add(&mut self.$field, Box::new(other));
+            self.$after();
+        }
+
+        fn sub(&mut self, other: Box<dyn StatData>) {
+            let other = if let Some(other) = other.downcast_ref::<$wrapper>() {
+                other.$field
+            } else if let Some(other) = other.downcast_ref::<$ty>() {
+                *other
+            } else {
+                return;
+            };
+            StatData::sub(&mut self.$field, Box::new(other));
+            self.$after();
+        }
+
+        fn apply_percent(&mut self, percent: f64) {
+            StatData::apply_percent(&mut self.$field, percent);
+            self.$after();
+        }
+
+        fn set_from(&mut self, other: &dyn StatData) -> bool {
+            let Some(other) = other.downcast_ref::<$ty>() else {
+                return false;
+            };
+            self.set(*other);
+            true
+        }
+    };
+}
+
+pub(crate) use wrapped_numeric_stat_ops;
+
+macro_rules! impl_bounded_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for BoundedStat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(BoundedStat::new(self.min, self.min, self.max))
+                }
+
+                wrapped_numeric_stat_ops!(BoundedStat<$ty>, $ty, value, clamp);
+            }
+        )*
+    };
+}
+
+impl_bounded_stat!(
+    Duration => "BoundedStat<Duration>",
+    u128 => "BoundedStat<u128>",
+    u64 => "BoundedStat<u64>",
+    u32 => "BoundedStat<u32>",
+    u16 => "BoundedStat<u16>",
+    u8 => "BoundedStat<u8>",
+    f64 => "BoundedStat<f64>",
+    f32 => "BoundedStat<f32>",
+    i128 => "BoundedStat<i128>",
+    i64 => "BoundedStat<i64>",
+    i32 => "BoundedStat<i32>",
+    i16 => "BoundedStat<i16>",
+    i8 => "BoundedStat<i8>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{stat_modification::ModificationType, test_utils::Health, Stats};
+
+    use super::*;
+
+    #[test]
+    fn bounded_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Health, Box::new(BoundedStat::new(50i32, 0, 100)));
+
+        stats.add_to_stat(&Health, Box::new(80i32));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<BoundedStat<i32>>(&Health)
+                .unwrap()
+                .value(),
+            100
+        );
+
+        stats.sub_from_stat(&Health, Box::new(BoundedStat::new(150i32, 0, 200)));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<BoundedStat<i32>>(&Health)
+                .unwrap()
+                .value(),
+            0
+        );
+
+        stats.set_stat(&Health, Box::new(BoundedStat::new(30i32, 10, 20)));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<BoundedStat<i32>>(&Health)
+                .unwrap()
+                .value(),
+            20
+        );
+
+        stats.reset_stat(&Health);
+        assert_eq!(
+            *stats
+                .get_stat_downcast::<BoundedStat<i32>>(&Health)
+                .unwrap(),
+            BoundedStat::new(10i32, 10, 20)
+        );
+    }
+
+    #[test]
+    fn bounded_duration_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(
+            &Health,
+            Box::new(BoundedStat::new(
+                Duration::from_secs(5),
+                Duration::from_secs(2),
+                Duration::from_secs(10),
+            )),
+        );
+
+        stats.sub_from_stat(&Health, Box::new(Duration::from_secs(30)));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<BoundedStat<Duration>>(&Health)
+                .unwrap()
+                .value(),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn set_bounded_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Health, Box::new(BoundedStat::new(50i32, 0, 100)));
+
+        stats.apply_modification(&Health, ModificationType::set(500i32));
+        assert_eq!(
+            *stats
+                .get_stat_downcast::<BoundedStat<i32>>(&Health)
+                .unwrap(),
+            BoundedStat::new(100i32, 0, 100)
+        );
+
+        stats.force_set(&Health, Box::new(500i32));
+        assert_eq!(stats.get_stat_downcast::<i32>(&Health), Some(&500));
+    }
+}
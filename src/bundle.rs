@@ -0,0 +1,184 @@
+//! Saving and loading every registered stat resource as a single serializable bundle.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "warn_on_missing")]
+use bevy::log::warn;
+use bevy::{
+    app::App,
+    prelude::{Resource, World},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{MergeMode, Stats};
+
+/// A save and load function for a single registered stat resource
+struct RegisteredStatResource {
+    name: &'static str,
+    save: fn(&World) -> Option<Stats>,
+    load: fn(&mut World, Stats),
+}
+
+/// Every stat resource registered with [`StatAppExt::register_stat_resource`](crate::StatAppExt::register_stat_resource)
+#[derive(Resource, Default)]
+struct StatResourceRegistry {
+    resources: Vec<RegisteredStatResource>,
+}
+
+pub(crate) fn register_stat_resource<StatCollection: crate::StatCollection + Resource>(
+    app: &mut App,
+) {
+    let mut registry = app
+        .world_mut()
+        .get_resource_or_init::<StatResourceRegistry>();
+    let name = std::any::type_name::<StatCollection>();
+    if registry
+        .resources
+        .iter()
+        .any(|resource| resource.name == name)
+    {
+        return;
+    }
+    registry.resources.push(RegisteredStatResource {
+        name,
+        save: |world| {
+            world
+                .get_resource::<StatCollection>()
+                .map(|stat_collection| stat_collection.stats().clone())
+        },
+        load: |world, stats| {
+            if let Some(mut stat_collection) = world.get_resource_mut::<StatCollection>() {
+                stat_collection
+                    .stats_mut()
+                    .merge_with(stats, MergeMode::Overwrite);
+            }
+        },
+    });
+}
+
+/// The [`Stats`] of every registered stat resource keyed by the resources type name.
+///
+/// Create one with [`SaveBundle::save`] or [`StatAppExt::save_all_stats`](crate::StatAppExt::save_all_stats) and
+/// serialize it with any serde format to save the whole game in one file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SaveBundle {
+    collections: BTreeMap<String, Stats>,
+}
+
+impl SaveBundle {
+    /// Clones the [`Stats`] of every registered stat resource in the world
+    pub fn save(world: &World) -> SaveBundle {
+        let mut collections = BTreeMap::new();
+        if let Some(registry) = world.get_resource::<StatResourceRegistry>() {
+            for resource in registry.resources.iter() {
+                if let Some(stats) = (resource.save)(world) {
+                    collections.insert(resource.name.to_string(), stats);
+                }
+            }
+        }
+        SaveBundle { collections }
+    }
+
+    /// Merges each saved [`Stats`] over the registered stat resource with the same type name.
+    ///
+    /// Saved stats overwrite existing stats with the same id, see [`Stats::merge_with`]. Collections whose resource
+    /// isn't registered are skipped
+    pub fn load(self, world: &mut World) {
+        let loaders = world
+            .get_resource::<StatResourceRegistry>()
+            .map(|registry| {
+                registry
+                    .resources
+                    .iter()
+                    .map(|resource| (resource.name, resource.load))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        for (name, stats) in self.collections {
+            let Some((_, load)) = loaders.iter().find(|(loader_name, _)| *loader_name == name)
+            else {
+                #[cfg(feature = "warn_on_missing")]
+                warn!(
+                    "Failed to load stats: {} is not a registered stat resource",
+                    name
+                );
+                continue;
+            };
+            load(world, stats);
+        }
+    }
+
+    /// Returns the saved [`Stats`] for the given type name
+    pub fn get(&self, type_name: &str) -> Option<&Stats> {
+        self.collections.get(type_name)
+    }
+
+    /// Returns an iterator over every saved type name and its [`Stats`] sorted by type name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Stats)> {
+        self.collections
+            .iter()
+            .map(|(name, stats)| (name.as_str(), stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Resource;
+
+    use crate::{
+        test_utils::{impl_test_stat_collection, Gold},
+        StatAppExt,
+    };
+
+    use super::*;
+
+    #[derive(Resource, Default)]
+    pub struct PlayerStats {
+        stats: Stats,
+    }
+
+    #[derive(Resource, Default)]
+    pub struct WorldStats {
+        stats: Stats,
+    }
+
+    impl_test_stat_collection!(PlayerStats, WorldStats);
+
+    #[test]
+    fn save_bundle() {
+        let mut app = App::new();
+        app.register_stat_resource::<PlayerStats>();
+        app.register_stat_resource::<WorldStats>();
+        app.world_mut()
+            .resource_mut::<PlayerStats>()
+            .stats
+            .set_stat(&Gold, Box::new(5u64));
+        app.world_mut()
+            .resource_mut::<WorldStats>()
+            .stats
+            .set_stat(&Gold, Box::new(100u64));
+
+        let json = serde_json::to_string(&app.save_all_stats()).unwrap();
+
+        let mut loaded = App::new();
+        loaded.register_stat_resource::<PlayerStats>();
+        loaded.register_stat_resource::<WorldStats>();
+        loaded.load_all_stats(serde_json::from_str::<SaveBundle>(&json).unwrap());
+        assert_eq!(
+            loaded
+                .world()
+                .resource::<PlayerStats>()
+                .stats
+                .get_stat_downcast::<u64>(&Gold),
+            Some(&5)
+        );
+        assert_eq!(
+            loaded
+                .world()
+                .resource::<WorldStats>()
+                .stats
+                .get_stat_downcast::<u64>(&Gold),
+            Some(&100)
+        );
+    }
+}
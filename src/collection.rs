@@ -0,0 +1,79 @@
+//! The bounds shared by every resource and component that holds a [`Stats`].
+
+use crate::{stat_modification::ModificationType, StatIdentifier, Stats};
+
+mod sealed {
+    use crate::Stats;
+
+    pub trait Sealed {}
+
+    impl<T: AsMut<Stats> + AsRef<Stats> + Send + Sync + 'static> Sealed for T {}
+}
+
+/// A resource or component that holds a [`Stats`], such as one registered with
+/// [`StatAppExt::register_stat_resource`](crate::StatAppExt::register_stat_resource).
+///
+/// Implemented for every type that is `AsMut<Stats> + AsRef<Stats> + Send + Sync + 'static`, it can't be implemented
+/// manually. APIs that only read stats, such as [`StatReader`](crate::StatReader), just require `AsRef<Stats>`
+pub trait StatCollection:
+    AsMut<Stats> + AsRef<Stats> + Send + Sync + 'static + sealed::Sealed
+{
+    /// Returns the contained [`Stats`]
+    fn stats(&self) -> &Stats {
+        self.as_ref()
+    }
+
+    /// Returns the contained [`Stats`] mutably
+    fn stats_mut(&mut self) -> &mut Stats {
+        self.as_mut()
+    }
+
+    /// Applies the given [`ModificationType`] to the given str id immediately, see
+    /// [`Stats::apply_modification_manual`]
+    fn modify_manual(&mut self, stat_id: &str, modification_type: ModificationType) {
+        self.stats_mut()
+            .apply_modification_manual(stat_id, modification_type);
+    }
+
+    /// Applies the given [`ModificationType`] to the requested [`StatIdentifier`] immediately.
+    ///
+    /// Works through a `Mut<StatCollection>` from a query or `ResMut`, so systems that already have mutable access
+    /// don't need to go through commands or events
+    fn modify(&mut self, stat_id: &impl StatIdentifier, modification_type: ModificationType) {
+        self.modify_manual(stat_id.identifier(), modification_type);
+    }
+}
+
+impl<T: AsMut<Stats> + AsRef<Stats> + Send + Sync + 'static> StatCollection for T {}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::{Query, World};
+
+    use crate::test_utils::{EnemiesKilled, EntityStats};
+
+    use super::*;
+
+    #[test]
+    fn modify_through_query() {
+        let mut world = World::new();
+        let entity = world.spawn(EntityStats::default()).id();
+        let system = world.register_system(|mut query: Query<&mut EntityStats>| {
+            for mut stats in query.iter_mut() {
+                stats.modify(&EnemiesKilled, ModificationType::add(5u64));
+                stats.modify(&EnemiesKilled, ModificationType::add(2u64));
+            }
+        });
+        world.run_system(system).unwrap();
+
+        assert_eq!(
+            world
+                .entity(entity)
+                .get::<EntityStats>()
+                .unwrap()
+                .stats
+                .get_stat_downcast::<u64>(&EnemiesKilled),
+            Some(&7u64)
+        );
+    }
+}
@@ -1,23 +1,52 @@
 use std::marker::PhantomData;
 
 use bevy::{
-    ecs::system::{EntityCommand, EntityCommands},
-    prelude::{Commands, Component, Entity, World},
+    ecs::{
+        system::{EntityCommand, EntityCommands},
+        world::{EntityRef, EntityWorldMut},
+    },
+    prelude::{Commands, Component, DetectChangesMut, Entity, Event, World},
 };
 
-use crate::{stat_modification::ModificationType, StatData, StatIdentifier, Stats};
+#[cfg(feature = "warn_on_missing")]
+use bevy::log::warn;
+
+use crate::{
+    stat_modification::{ModificationKind, ModificationType},
+    StatData, StatIdentifier, Stats,
+};
+
+/// An observer event triggered on an entity after one of its stats was changed with
+/// [`StatEntityCommandsExt::modify_stat_observed`] or [`StatCommandsExt::modify_stat_observed`].
+///
+/// Modifications that leave the stat unchanged don't trigger one, see [`Stats::apply_modification_manual`]
+#[derive(Event)]
+pub struct StatModified<StatCollection: crate::StatCollection> {
+    stat_id: String,
+    kind: ModificationKind,
+    pd: PhantomData<StatCollection>,
+}
+
+impl<StatCollection: crate::StatCollection> StatModified<StatCollection> {
+    /// The id of the stat that was modified
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// The kind of modification that was applied, see [`ModificationType::reported_kind`]
+    pub fn kind(&self) -> ModificationKind {
+        self.kind
+    }
+}
 
 /// Make changes to an entities stats in a deferred patter using commands.
-pub struct ModifyStatEntityCommands<
-    'a,
-    StatCollection: AsMut<Stats> + Send + Sync + 'static + Component,
-> {
+pub struct ModifyStatEntityCommands<'a, StatCollection: crate::StatCollection + Component> {
     target_entity: Entity,
     target_component: PhantomData<StatCollection>,
     commands: Commands<'a, 'a>,
 }
 
-impl<'a, StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>
+impl<'a, StatCollection: crate::StatCollection + Component>
     ModifyStatEntityCommands<'a, StatCollection>
 {
     /// Return the entity id that is targeted for stat changes
@@ -30,13 +59,26 @@ impl<'a, StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>
         self.target_entity = entity
     }
 
+    /// Returns a [`ModifyStatEntityCommands`] targeting a different stat collection component on the same entity.
+    ///
+    /// Commands queued through either builder are applied in the order they were queued
+    pub fn collection<OtherCollection: crate::StatCollection + Component>(
+        &mut self,
+    ) -> ModifyStatEntityCommands<'_, OtherCollection> {
+        ModifyStatEntityCommands {
+            target_entity: self.target_entity,
+            target_component: PhantomData,
+            commands: self.commands.reborrow(),
+        }
+    }
+
     /// Return a reference to the commands object contained
     pub fn commands(&mut self) -> &mut Commands<'a, 'a> {
         &mut self.commands
     }
 }
 
-impl<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>
+impl<StatCollection: crate::StatCollection + Component>
     ModifyStatEntityCommands<'_, StatCollection>
 {
     /// Get entity commands for the targeted entity
@@ -45,32 +87,38 @@ impl<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>
         self.commands().entity(id)
     }
 
-    /// Queue a command to perform an add with the given [`StatData`] to the targeted [`StatIdentifier`]
-    pub fn add(
+    /// Queue a command to apply the given [`ModificationType`] to the targeted [`StatIdentifier`].
+    ///
+    /// Accepts any prebuilt modification so add, sub, custom, etc. can be mixed in one chain
+    pub fn with(
         &mut self,
         stat_id: impl StatIdentifier + 'static + Send + Sync,
-        stat_data: impl StatData,
+        modification_type: ModificationType,
     ) -> &mut Self {
         self.entity_commands()
             .queue(modify_entity_stat::<StatCollection>(
                 stat_id,
-                ModificationType::add(stat_data),
+                modification_type,
             ));
         self
     }
 
+    /// Queue a command to perform an add with the given [`StatData`] to the targeted [`StatIdentifier`]
+    pub fn add(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> &mut Self {
+        self.with(stat_id, ModificationType::add(stat_data))
+    }
+
     /// Queue a command to perform a sub with the given [`StatData`] to the targeted [`StatIdentifier`]
     pub fn sub(
         &mut self,
         stat_id: impl StatIdentifier + 'static + Send + Sync,
         stat_data: impl StatData,
     ) -> &mut Self {
-        self.entity_commands()
-            .queue(modify_entity_stat::<StatCollection>(
-                stat_id,
-                ModificationType::sub(stat_data),
-            ));
-        self
+        self.with(stat_id, ModificationType::sub(stat_data))
     }
 
     /// Queue a command to perform a set with the given [`StatData`] to the targeted [`StatIdentifier`]
@@ -79,54 +127,73 @@ impl<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>
         stat_id: impl StatIdentifier + 'static + Send + Sync,
         stat_data: impl StatData,
     ) -> &mut Self {
-        self.entity_commands()
-            .queue(modify_entity_stat::<StatCollection>(
-                stat_id,
-                ModificationType::set(stat_data),
-            ));
-        self
+        self.with(stat_id, ModificationType::set(stat_data))
     }
 
     /// Queue a command to perform a remove to the targeted [`StatIdentifier`]
     pub fn remove(&mut self, stat_id: impl StatIdentifier + 'static + Send + Sync) -> &mut Self {
-        self.entity_commands()
-            .queue(modify_entity_stat::<StatCollection>(
-                stat_id,
-                ModificationType::remove(),
-            ));
-        self
+        self.with(stat_id, ModificationType::remove())
     }
 
     /// Queue a command to perform a reset to the targeted [`StatIdentifier`]
     pub fn reset(&mut self, stat_id: impl StatIdentifier + 'static + Send + Sync) -> &mut Self {
+        self.with(stat_id, ModificationType::reset())
+    }
+
+    /// Queue a command to perform a toggle to the targeted [`StatIdentifier`]
+    pub fn toggle(&mut self, stat_id: impl StatIdentifier + 'static + Send + Sync) -> &mut Self {
+        self.with(stat_id, ModificationType::toggle())
+    }
+
+    /// Queue a single command that applies every modification in order with one component lookup.
+    ///
+    /// Modifications that don't apply, such as adding mismatched [`StatData`], are skipped without affecting the rest
+    pub fn batch(
+        &mut self,
+        ops: Vec<(Box<dyn StatIdentifier + Send + Sync>, ModificationType)>,
+    ) -> &mut Self {
         self.entity_commands()
-            .queue(modify_entity_stat::<StatCollection>(
-                stat_id,
-                ModificationType::reset(),
-            ));
+            .queue(modify_entity_stats_batch::<StatCollection>(ops));
         self
     }
 }
 
 pub trait StatCommandsExt {
     /// Returns a [`ModifyStatEntityCommands`] object for the given entity
-    fn entity_stats<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn entity_stats<StatCollection: crate::StatCollection + Component>(
         &mut self,
         entity: Entity,
     ) -> ModifyStatEntityCommands<'_, StatCollection>;
 
     /// Modify a single stat on an entity
-    fn modify_stat<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn modify_stat<StatCollection: crate::StatCollection + Component>(
         &mut self,
         entity: Entity,
         stat_id: impl StatIdentifier + 'static + Send + Sync,
         modification_type: ModificationType,
     );
+
+    /// Modify a single stat on an entity and trigger a [`StatModified`] observer event on it if the stat changed
+    fn modify_stat_observed<StatCollection: crate::StatCollection + Component>(
+        &mut self,
+        entity: Entity,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        modification_type: ModificationType,
+    );
+
+    /// Spawns a new entity with a `StatCollection` component built from the given stats, see [`Stats::seed`].
+    ///
+    /// The stats exist as soon as the spawn is applied so any stat modifications queued on the returned
+    /// [`EntityCommands`] afterwards apply on top of the seeded values
+    fn spawn_with_stats<StatCollection: From<Stats> + Component>(
+        &mut self,
+        stats: Vec<(String, Box<dyn StatData>)>,
+    ) -> EntityCommands<'_>;
 }
 
 impl<'a> StatCommandsExt for Commands<'a, 'a> {
     /// Get access to an object to modify entity stats
-    fn entity_stats<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn entity_stats<StatCollection: crate::StatCollection + Component>(
         &mut self,
         entity: Entity,
     ) -> ModifyStatEntityCommands<'_, StatCollection> {
@@ -138,7 +205,7 @@ impl<'a> StatCommandsExt for Commands<'a, 'a> {
     }
 
     /// Modify a single stat on an entity
-    fn modify_stat<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn modify_stat<StatCollection: crate::StatCollection + Component>(
         &mut self,
         entity: Entity,
         stat_id: impl StatIdentifier + 'static + Send + Sync,
@@ -147,16 +214,40 @@ impl<'a> StatCommandsExt for Commands<'a, 'a> {
         self.entity(entity)
             .modify_stat::<StatCollection>(stat_id, modification_type);
     }
+
+    fn modify_stat_observed<StatCollection: crate::StatCollection + Component>(
+        &mut self,
+        entity: Entity,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        modification_type: ModificationType,
+    ) {
+        self.entity(entity)
+            .modify_stat_observed::<StatCollection>(stat_id, modification_type);
+    }
+
+    fn spawn_with_stats<StatCollection: From<Stats> + Component>(
+        &mut self,
+        stats: Vec<(String, Box<dyn StatData>)>,
+    ) -> EntityCommands<'_> {
+        self.spawn(StatCollection::from(Stats::seed(stats)))
+    }
 }
 
 pub trait StatEntityCommandsExt {
     /// Get a special command pattern object to affect changes to an entity
-    fn entity_stats<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn entity_stats<StatCollection: crate::StatCollection + Component>(
         &mut self,
     ) -> ModifyStatEntityCommands<'_, StatCollection>;
 
     /// Modify a single stat on an entity
-    fn modify_stat<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn modify_stat<StatCollection: crate::StatCollection + Component>(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        modification_type: ModificationType,
+    );
+
+    /// Modify a single stat on an entity and trigger a [`StatModified`] observer event on it if the stat changed
+    fn modify_stat_observed<StatCollection: crate::StatCollection + Component>(
         &mut self,
         stat_id: impl StatIdentifier + 'static + Send + Sync,
         modification_type: ModificationType,
@@ -164,7 +255,7 @@ pub trait StatEntityCommandsExt {
 }
 
 impl<'a> StatEntityCommandsExt for EntityCommands<'a> {
-    fn modify_stat<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn modify_stat<StatCollection: crate::StatCollection + Component>(
         &mut self,
         stat_id: impl StatIdentifier + 'static + Send + Sync,
         modification_type: ModificationType,
@@ -175,7 +266,18 @@ impl<'a> StatEntityCommandsExt for EntityCommands<'a> {
         ));
     }
 
-    fn entity_stats<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+    fn modify_stat_observed<StatCollection: crate::StatCollection + Component>(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        modification_type: ModificationType,
+    ) {
+        self.queue(modify_entity_stat_observed::<StatCollection>(
+            stat_id,
+            modification_type,
+        ));
+    }
+
+    fn entity_stats<StatCollection: crate::StatCollection + Component>(
         &mut self,
     ) -> ModifyStatEntityCommands<'_, StatCollection> {
         ModifyStatEntityCommands {
@@ -186,56 +288,120 @@ impl<'a> StatEntityCommandsExt for EntityCommands<'a> {
     }
 }
 
-fn modify_entity_stat<StatCollection: AsMut<Stats> + Send + Sync + 'static + Component>(
+pub trait StatQueryExt {
+    /// Gets the stat for the requested [`StatIdentifier`] from the entities `StatCollection` component and attempts to downcast it into the given type
+    fn stat<StatCollection: AsRef<Stats> + Component, Stat: StatData + 'static>(
+        &self,
+        stat_id: &impl StatIdentifier,
+    ) -> Option<&Stat>;
+}
+
+impl StatQueryExt for EntityRef<'_> {
+    fn stat<StatCollection: AsRef<Stats> + Component, Stat: StatData + 'static>(
+        &self,
+        stat_id: &impl StatIdentifier,
+    ) -> Option<&Stat> {
+        self.get::<StatCollection>()?
+            .as_ref()
+            .get_stat_downcast::<Stat>(stat_id)
+    }
+}
+
+impl StatQueryExt for EntityWorldMut<'_> {
+    fn stat<StatCollection: AsRef<Stats> + Component, Stat: StatData + 'static>(
+        &self,
+        stat_id: &impl StatIdentifier,
+    ) -> Option<&Stat> {
+        self.get::<StatCollection>()?
+            .as_ref()
+            .get_stat_downcast::<Stat>(stat_id)
+    }
+}
+
+fn modify_entity_stat<StatCollection: crate::StatCollection + Component>(
     stat_id: impl StatIdentifier + 'static + Send + Sync,
     modification_type: ModificationType,
 ) -> impl EntityCommand {
     move |entity: Entity, world: &mut World| {
-        if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
-            if let Some(mut stat_collection) = entity_mut.get_mut::<StatCollection>() {
-                let stats = stat_collection.as_mut().as_mut();
-
-                match modification_type {
-                    ModificationType::Add(data) => {
-                        stats.add_to_stat_manual(stat_id.identifier(), data)
-                    }
-                    ModificationType::Sub(data) => {
-                        stats.sub_from_stat_manual(stat_id.identifier(), data)
-                    }
-                    ModificationType::Remove => stats.remove_stat_manual(stat_id.identifier()),
-                    ModificationType::Set(data) => {
-                        stats.set_stat_manual(stat_id.identifier(), data)
-                    }
-                    ModificationType::Reset => stats.reset_stat_manual(stat_id.identifier()),
-                }
-            }
-        }
+        with_entity_stats::<StatCollection>(entity, world, |stats| {
+            stats.apply_modification_manual(stat_id.identifier(), modification_type)
+        });
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[derive(Hash)]
-    pub struct EnemiesKilled;
-
-    impl StatIdentifier for EnemiesKilled {
-        fn identifier(&self) -> &'static str {
-            "Enemies Killed"
+fn modify_entity_stat_observed<StatCollection: crate::StatCollection + Component>(
+    stat_id: impl StatIdentifier + 'static + Send + Sync,
+    modification_type: ModificationType,
+) -> impl EntityCommand {
+    move |entity: Entity, world: &mut World| {
+        let kind = modification_type.reported_kind();
+        let changed = with_entity_stats::<StatCollection>(entity, world, |stats| {
+            stats.apply_modification_manual(stat_id.identifier(), modification_type)
+        });
+        if changed {
+            world.trigger_targets(
+                StatModified::<StatCollection> {
+                    stat_id: stat_id.identifier().to_string(),
+                    kind,
+                    pd: PhantomData,
+                },
+                entity,
+            );
         }
     }
+}
 
-    #[derive(Component)]
-    pub struct EntityStats {
-        stats: Stats,
+fn modify_entity_stats_batch<StatCollection: crate::StatCollection + Component>(
+    ops: Vec<(Box<dyn StatIdentifier + Send + Sync>, ModificationType)>,
+) -> impl EntityCommand {
+    move |entity: Entity, world: &mut World| {
+        with_entity_stats::<StatCollection>(entity, world, |stats| {
+            let mut changed = false;
+            for (stat_id, modification_type) in ops {
+                changed |= stats.apply_modification_manual(stat_id.identifier(), modification_type);
+            }
+            changed
+        });
     }
+}
 
-    impl AsMut<Stats> for EntityStats {
-        fn as_mut(&mut self) -> &mut Stats {
-            &mut self.stats
-        }
+/// Runs the given function on the [`Stats`] of the entities `StatCollection` component, returning true if it changed
+/// a stat.
+///
+/// The component is only marked as changed for `Changed<StatCollection>` queries when the function returns true.
+/// Returns false if the entity doesn't exist or doesn't have the component. With the `warn_on_missing` feature a
+/// warning is logged when that happens.
+fn with_entity_stats<StatCollection: crate::StatCollection + Component>(
+    entity: Entity,
+    world: &mut World,
+    f: impl FnOnce(&mut Stats) -> bool,
+) -> bool {
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        #[cfg(feature = "warn_on_missing")]
+        warn!("Failed to modify stats: entity {} does not exist", entity);
+        return false;
+    };
+    let Some(mut stat_collection) = entity_mut.get_mut::<StatCollection>() else {
+        #[cfg(feature = "warn_on_missing")]
+        warn!(
+            "Failed to modify stats: entity {} does not have a {} component",
+            entity,
+            std::any::type_name::<StatCollection>()
+        );
+        return false;
+    };
+    let changed = f(stat_collection.bypass_change_detection().stats_mut());
+    if changed {
+        stat_collection.set_changed();
     }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{impl_test_stat_collection, EnemiesKilled, EntityStats, PlayTime};
+
+    use super::*;
 
     #[test]
     fn entity_commands() {
@@ -314,4 +480,178 @@ mod tests {
             None
         );
     }
+
+    #[derive(Component, Default)]
+    pub struct EquipmentStats {
+        stats: Stats,
+    }
+
+    impl_test_stat_collection!(EquipmentStats);
+
+    #[test]
+    fn chained_entity_commands() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((EntityStats::from(Stats::new()), EquipmentStats::default()))
+            .id();
+
+        let mut commands = world.commands();
+        commands
+            .entity_stats::<EntityStats>(entity)
+            .add(EnemiesKilled, 5u64)
+            .with(EnemiesKilled, ModificationType::add_percent(100.0))
+            .with(
+                PlayTime,
+                ModificationType::custom(|stat| stat.add(Box::new(1u64))),
+            )
+            .sub(EnemiesKilled, 1u64)
+            .collection::<EquipmentStats>()
+            .set(PlayTime, 3u32)
+            .add(PlayTime, 1u32);
+        world.flush();
+
+        let stats = &world.entity(entity).get::<EntityStats>().unwrap().stats;
+        assert_eq!(stats.get_stat_downcast::<u64>(&EnemiesKilled), Some(&9));
+        assert!(!stats.contains_stat(&PlayTime));
+        let equipment = &world.entity(entity).get::<EquipmentStats>().unwrap().stats;
+        assert_eq!(equipment.get_stat_downcast::<u32>(&PlayTime), Some(&4));
+    }
+
+    #[test]
+    fn batch_commands() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(EntityStats {
+                stats: Stats::new(),
+            })
+            .id();
+
+        let mut commands = world.commands();
+        let mut stats = commands.entity_stats::<EntityStats>(entity);
+        stats.batch(vec![
+            (Box::new(EnemiesKilled), ModificationType::add(5u64)),
+            (Box::new(PlayTime), ModificationType::set(2.5f32)),
+            (Box::new(EnemiesKilled), ModificationType::add(2.0f32)),
+            (Box::new(EnemiesKilled), ModificationType::sub(1u64)),
+        ]);
+        world.flush();
+
+        let stats = &world.entity(entity).get::<EntityStats>().unwrap().stats;
+        assert_eq!(
+            *stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(),
+            4u64
+        );
+        assert_eq!(*stats.get_stat_downcast::<f32>(&PlayTime).unwrap(), 2.5f32);
+    }
+
+    #[test]
+    fn query_stats() {
+        let mut world = World::new();
+        let entity = world
+            .spawn(EntityStats {
+                stats: Stats::new(),
+            })
+            .id();
+
+        world
+            .commands()
+            .entity_stats::<EntityStats>(entity)
+            .add(EnemiesKilled, 5u64);
+        world.flush();
+
+        assert_eq!(
+            world
+                .entity(entity)
+                .stat::<EntityStats, u64>(&EnemiesKilled),
+            Some(&5u64)
+        );
+        assert_eq!(
+            world
+                .entity_mut(entity)
+                .stat::<EntityStats, f32>(&EnemiesKilled),
+            None
+        );
+        assert_eq!(
+            world.entity(entity).stat::<EntityStats, u64>(&PlayTime),
+            None
+        );
+    }
+
+    #[test]
+    fn observed_modifications() {
+        use bevy::prelude::{ResMut, Resource, Trigger};
+
+        #[derive(Resource, Default)]
+        struct Observed(Vec<(String, ModificationKind)>);
+
+        let mut world = World::new();
+        world.init_resource::<Observed>();
+        let entity = world
+            .spawn(EntityStats {
+                stats: Stats::new(),
+            })
+            .observe(
+                |trigger: Trigger<StatModified<EntityStats>>, mut observed: ResMut<Observed>| {
+                    observed.0.push((
+                        trigger.event().stat_id().to_string(),
+                        trigger.event().kind(),
+                    ));
+                },
+            )
+            .id();
+
+        let mut commands = world.commands();
+        commands
+            .entity(entity)
+            .modify_stat_observed::<EntityStats>(EnemiesKilled, ModificationType::add(5u64));
+        commands
+            .entity(entity)
+            .modify_stat::<EntityStats>(EnemiesKilled, ModificationType::add(5u64));
+        // Ignored because the types differ, and because 1 is less than the current value
+        commands.entity(entity).modify_stat_observed::<EntityStats>(
+            EnemiesKilled,
+            ModificationType::sub(String::from("Slime")),
+        );
+        commands
+            .entity(entity)
+            .modify_stat_observed::<EntityStats>(EnemiesKilled, ModificationType::set_max(1u64));
+        commands.modify_stat_observed::<EntityStats>(
+            entity,
+            EnemiesKilled,
+            ModificationType::reset(),
+        );
+        world.flush();
+
+        assert_eq!(
+            world.resource::<Observed>().0,
+            vec![
+                ("Enemies Killed".to_string(), ModificationKind::Add),
+                ("Enemies Killed".to_string(), ModificationKind::Reset)
+            ]
+        );
+    }
+
+    #[test]
+    fn spawn_with_stats() {
+        let mut world = World::new();
+
+        let mut commands = world.commands();
+        let mut entity_commands = commands.spawn_with_stats::<EntityStats>(vec![
+            ("Enemies Killed".to_string(), Box::new(0u64)),
+            ("Playtime".to_string(), Box::new(1.5f32)),
+        ]);
+        entity_commands.modify_stat::<EntityStats>(EnemiesKilled, ModificationType::add(2u64));
+        let entity = entity_commands.id();
+        world.flush();
+
+        let entity_ref = world.entity(entity);
+        assert_eq!(
+            entity_ref.stat::<EntityStats, u64>(&EnemiesKilled),
+            Some(&2u64)
+        );
+        assert_eq!(
+            entity_ref.stat::<EntityStats, f32>(&PlayTime),
+            Some(&1.5f32)
+        );
+    }
 }
@@ -0,0 +1,133 @@
+//! A [`StatData`] that counts how many times each kind of discrete event happened.
+
+use std::collections::BTreeMap;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// Counts occurrences per string key, like `{"crit": 42, "miss": 8}`.
+///
+/// - `add` merges the counts of another [`CounterStat`] into this one
+/// - `sub` decrements the counts by another [`CounterStat`], saturating at zero. Keys that reach zero are removed
+/// - `default` is an empty counter
+///
+/// Use [`Stats::get_stat_downcast_mut`](crate::Stats::get_stat_downcast_mut) to call [`CounterStat::increment`] on a
+/// stored counter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct CounterStat {
+    counts: BTreeMap<String, u64>,
+}
+
+impl CounterStat {
+    /// Creates a new empty [`CounterStat`]
+    pub fn new() -> Self {
+        Self {
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Increments the count for the given key by one
+    pub fn increment(&mut self, key: impl Into<String>) {
+        self.increment_by(key, 1);
+    }
+
+    /// Increments the count for the given key by the given amount
+    pub fn increment_by(&mut self, key: impl Into<String>, amount: u64) {
+        let count = self.counts.entry(key.into()).or_default();
+        *count = count.saturating_add(amount);
+    }
+
+    /// Returns the count for the given key, zero if it was never counted
+    pub fn get(&self, key: &str) -> u64 {
+        self.counts.get(key).copied().unwrap_or_default()
+    }
+
+    /// Returns every key and its count sorted by key
+    pub fn counts(&self) -> &BTreeMap<String, u64> {
+        &self.counts
+    }
+
+    /// Returns the sum of every count
+    pub fn total(&self) -> u64 {
+        self.counts
+            .values()
+            .fold(0, |total, count| total.saturating_add(*count))
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for CounterStat {
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(CounterStat::new())
+    }
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<CounterStat>() {
+            for (key, count) in other.counts.iter() {
+                self.increment_by(key.clone(), *count);
+            }
+        }
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<CounterStat>() {
+            for (key, amount) in other.counts.iter() {
+                let Some(count) = self.counts.get_mut(key) else {
+                    continue;
+                };
+                *count = count.saturating_sub(*amount);
+                if *count == 0 {
+                    self.counts.remove(key);
+                }
+            }
+        }
+    }
+
+    fn eq_dyn(&self, other: &dyn StatData) -> bool {
+        other.downcast_ref::<CounterStat>() == Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::Hits, Stats};
+
+    use super::*;
+
+    #[test]
+    fn counter_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Hits, Box::new(CounterStat::new()));
+
+        let hits = stats.get_stat_downcast_mut::<CounterStat>(&Hits).unwrap();
+        hits.increment("crit");
+        hits.increment("crit");
+        hits.increment("miss");
+
+        let mut other = CounterStat::new();
+        other.increment_by("crit", 40);
+        other.increment_by("miss", 7);
+        stats.add_to_stat(&Hits, Box::new(other));
+
+        let hits = stats.get_stat_downcast::<CounterStat>(&Hits).unwrap();
+        assert_eq!(hits.get("crit"), 42);
+        assert_eq!(hits.get("miss"), 8);
+        assert_eq!(hits.total(), 50);
+
+        let mut other = CounterStat::new();
+        other.increment_by("crit", 2);
+        other.increment_by("miss", 100);
+        other.increment_by("block", 1);
+        stats.sub_from_stat(&Hits, Box::new(other));
+
+        let hits = stats.get_stat_downcast::<CounterStat>(&Hits).unwrap();
+        assert_eq!(hits.get("crit"), 40);
+        assert_eq!(hits.get("miss"), 0);
+        assert_eq!(hits.counts().len(), 1);
+        assert_eq!(hits.total(), 40);
+    }
+}
@@ -0,0 +1,82 @@
+//! An egui window for inspecting a stat resource while debugging.
+
+use bevy::prelude::{Local, Res, Resource};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::Stats;
+
+/// Draws every stat in the given resource in an egui window along with its [`Debug`] value, sorted by id.
+///
+/// The window is redrawn every frame and has a filter box that only shows the stats whose id contains the entered
+/// text, ignoring case. The `Copy all` button copies [`Stats::to_display_string`] to the clipboard. Add
+/// `bevy_egui`'s `EguiPlugin` and then the system for each resource to inspect:
+///
+/// ```ignore
+/// app.add_plugins(EguiPlugin)
+///     .add_systems(Update, stats_debug_window::<ResourceStats>);
+/// ```
+///
+/// Does nothing if there is no primary window to draw into
+pub fn stats_debug_window<StatCollection: AsRef<Stats> + Send + Sync + 'static + Resource>(
+    mut contexts: EguiContexts,
+    stat_collection: Res<StatCollection>,
+    mut filter: Local<String>,
+) {
+    let Some(ctx) = contexts.try_ctx_mut() else {
+        return;
+    };
+    let stats: &Stats = (*stat_collection).as_ref();
+    let title = std::any::type_name::<StatCollection>()
+        .rsplit("::")
+        .next()
+        .unwrap_or("Stats");
+    egui::Window::new(title).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Filter");
+            ui.text_edit_singleline(&mut *filter);
+            if ui.button("Copy all").clicked() {
+                ui.ctx().copy_text(stats.to_display_string());
+            }
+        });
+        ui.separator();
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for line in filtered_stat_lines(stats, &filter) {
+                ui.monospace(line);
+            }
+        });
+    });
+}
+
+/// Formats every stat whose id contains the filter, ignoring case, as `id: value` sorted by id
+fn filtered_stat_lines(stats: &Stats, filter: &str) -> Vec<String> {
+    let filter = filter.to_lowercase();
+    let mut matching: Vec<_> = stats
+        .iter()
+        .filter(|(stat_id, _)| stat_id.to_lowercase().contains(&filter))
+        .collect();
+    matching.sort_unstable_by_key(|(stat_id, _)| *stat_id);
+    matching
+        .into_iter()
+        .map(|(stat_id, stat)| format!("{stat_id}: {stat:?}"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_stat_lines() {
+        let mut stats = Stats::new();
+        stats.set_stat_manual("combat/kills", Box::new(3u64));
+        stats.set_stat_manual("Combat/Deaths", Box::new(1u64));
+        stats.set_stat_manual("crafting/items", Box::new(7u64));
+
+        assert_eq!(
+            filtered_stat_lines(&stats, "combat"),
+            vec!["Combat/Deaths: 1", "combat/kills: 3"]
+        );
+        assert_eq!(filtered_stat_lines(&stats, "").len(), 3);
+        assert!(filtered_stat_lines(&stats, "fishing").is_empty());
+    }
+}
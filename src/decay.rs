@@ -0,0 +1,280 @@
+//! Stats in a resource that automatically decay towards a floor over time, such as a rage meter.
+
+use std::marker::PhantomData;
+
+use bevy::{
+    app::{App, PostUpdate},
+    prelude::{DetectChangesMut, IntoSystemConfigs, Res, ResMut, Resource},
+    time::Time,
+};
+
+use crate::{StatSystemSets, Stats};
+
+/// A stat registered with [`StatAppExt::register_stat_decay`] that loses `rate` every second until it reaches
+/// `floor`.
+///
+/// Only `f32` and `f64` stats decay, stats of other types or that don't exist are left alone.
+///
+/// [`StatAppExt::register_stat_decay`]: crate::StatAppExt::register_stat_decay
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecayStat {
+    stat_id: String,
+    rate: f64,
+    floor: f64,
+    enabled: bool,
+}
+
+impl DecayStat {
+    /// The id of the decaying stat
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// How much the stat loses every second
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// The value the stat stops decaying at
+    pub fn floor(&self) -> f64 {
+        self.floor
+    }
+
+    /// Returns true if the stat is currently decaying
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Decays the given stats by the elapsed seconds, never pushing it below the floor.
+    ///
+    /// Returns true if the stat was above the floor and decayed
+    fn decay(&self, stats: &mut Stats, delta_seconds: f64) -> bool {
+        let amount = self.rate * delta_seconds;
+        let floor = self.floor;
+        let Some(stat) = stats.get_stat_manual(&self.stat_id) else {
+            return false;
+        };
+        if let Some(value) = stat.downcast_ref::<f64>() {
+            *value > floor
+                && stats.map_stat_downcast_manual::<f64>(&self.stat_id, |value| {
+                    *value = (*value - amount).max(floor);
+                })
+        } else if let Some(value) = stat.downcast_ref::<f32>() {
+            *value > floor as f32
+                && stats.map_stat_downcast_manual::<f32>(&self.stat_id, |value| {
+                    *value = (*value - amount as f32).max(floor as f32);
+                })
+        } else {
+            false
+        }
+    }
+}
+
+/// Every [`DecayStat`] registered for a stat resource.
+///
+/// Decay can be paused and resumed at runtime, for example to only decay rage while out of combat
+#[derive(Resource)]
+pub struct StatDecays<StatCollection> {
+    decays: Vec<DecayStat>,
+    pd: PhantomData<StatCollection>,
+}
+
+impl<StatCollection> StatDecays<StatCollection> {
+    /// Returns an iterator over every registered [`DecayStat`]
+    pub fn iter(&self) -> impl Iterator<Item = &DecayStat> {
+        self.decays.iter()
+    }
+
+    /// Pauses or resumes decay of the given str id, returning false if it was never registered
+    pub fn set_enabled_manual(&mut self, stat_id: &str, enabled: bool) -> bool {
+        let mut found = false;
+        for decay in self
+            .decays
+            .iter_mut()
+            .filter(|decay| decay.stat_id == stat_id)
+        {
+            decay.enabled = enabled;
+            found = true;
+        }
+        found
+    }
+
+    /// Pauses or resumes decay of the requested [`StatIdentifier`](crate::StatIdentifier), see
+    /// [`StatDecays::set_enabled_manual`]
+    pub fn set_enabled(&mut self, stat_id: &impl crate::StatIdentifier, enabled: bool) -> bool {
+        self.set_enabled_manual(stat_id.identifier(), enabled)
+    }
+}
+
+pub(crate) fn register_stat_decay<StatCollection: crate::StatCollection + Resource>(
+    app: &mut App,
+    stat_id: String,
+    rate: f64,
+    floor: f64,
+) {
+    if !app
+        .world()
+        .contains_resource::<StatDecays<StatCollection>>()
+    {
+        crate::configure_stat_system_sets(app);
+        app.insert_resource(StatDecays::<StatCollection> {
+            decays: Vec::new(),
+            pd: PhantomData,
+        });
+        app.add_systems(
+            PostUpdate,
+            decay_stats::<StatCollection>.in_set(StatSystemSets::BeforeModifications),
+        );
+    }
+    app.world_mut()
+        .resource_mut::<StatDecays<StatCollection>>()
+        .decays
+        .push(DecayStat {
+            stat_id,
+            rate,
+            floor,
+            enabled: true,
+        });
+}
+
+/// Runs before [`StatSystemSets::ApplyModifications`] so modifications sent this frame are applied on top of the
+/// decayed value rather than being decayed themselves
+fn decay_stats<StatCollection: crate::StatCollection + Resource>(
+    time: Res<Time>,
+    decays: Res<StatDecays<StatCollection>>,
+    mut stat_collection: ResMut<StatCollection>,
+) {
+    let delta_seconds = time.delta_secs_f64();
+    if delta_seconds <= 0.0 {
+        return;
+    }
+    // Only mark the resource as changed if a stat decayed
+    let stats = stat_collection.bypass_change_detection().stats_mut();
+    let mut changed = false;
+    for decay in decays.decays.iter().filter(|decay| decay.enabled) {
+        changed |= decay.decay(stats, delta_seconds);
+    }
+    if changed {
+        stat_collection.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::prelude::{DetectChanges, EventWriter};
+
+    use crate::{test_utils::ResourceStats, ModifyStat, StatAppExt, StatIdentifier};
+
+    use super::*;
+
+    pub struct RageMeter;
+
+    impl StatIdentifier for RageMeter {
+        fn identifier(&self) -> &'static str {
+            "Rage Meter"
+        }
+    }
+
+    fn rage(app: &App) -> f32 {
+        *app.world()
+            .resource::<ResourceStats>()
+            .stats
+            .get_stat_downcast::<f32>(&RageMeter)
+            .unwrap()
+    }
+
+    fn advance(app: &mut App, seconds: u64) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs(seconds));
+        app.update();
+    }
+
+    #[test]
+    fn stat_decay() {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.register_stat_resource::<ResourceStats>();
+        app.register_stat_decay::<ResourceStats>(RageMeter, 5.0, 2.0);
+        app.world_mut()
+            .resource_mut::<ResourceStats>()
+            .stats
+            .set_stat(&RageMeter, Box::new(20f32));
+
+        advance(&mut app, 2);
+        assert_eq!(rage(&app), 10.0);
+
+        // Adds sent this frame are applied after the decay
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::add(RageMeter, 5f32));
+        advance(&mut app, 1);
+        assert_eq!(rage(&app), 10.0);
+
+        advance(&mut app, 10);
+        assert_eq!(rage(&app), 2.0);
+
+        app.world_mut()
+            .resource_mut::<StatDecays<ResourceStats>>()
+            .set_enabled(&RageMeter, false);
+        app.add_systems(
+            PostUpdate,
+            (|mut writer: EventWriter<ModifyStat<ResourceStats>>| {
+                writer.send(ModifyStat::add(RageMeter, 1f32));
+            })
+            .before(StatSystemSets::BeforeModifications),
+        );
+        advance(&mut app, 1);
+        assert_eq!(rage(&app), 3.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct ChangedFrames(u32);
+
+    fn count_changed_frames(stats: Res<ResourceStats>, mut frames: ResMut<ChangedFrames>) {
+        if stats.is_changed() {
+            frames.0 += 1;
+        }
+    }
+
+    #[test]
+    fn stat_decay_at_floor() {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.init_resource::<ChangedFrames>();
+        app.insert_resource(ResourceStats {
+            stats: Stats::with_change_tracking(),
+        });
+        app.register_stat_resource::<ResourceStats>();
+        app.register_stat_decay::<ResourceStats>(RageMeter, 5.0, 2.0);
+        app.add_systems(
+            PostUpdate,
+            count_changed_frames.after(StatSystemSets::BeforeModifications),
+        );
+        app.world_mut()
+            .resource_mut::<ResourceStats>()
+            .stats
+            .set_stat(&RageMeter, Box::new(3f32));
+
+        advance(&mut app, 1);
+        assert_eq!(rage(&app), 2.0);
+        let last_modified = app
+            .world()
+            .resource::<ResourceStats>()
+            .stats
+            .last_modified(&RageMeter);
+
+        // Stats already at the floor are left alone and don't mark the resource as changed
+        advance(&mut app, 1);
+        advance(&mut app, 1);
+        assert_eq!(app.world().resource::<ChangedFrames>().0, 1);
+        assert_eq!(
+            app.world()
+                .resource::<ResourceStats>()
+                .stats
+                .last_modified(&RageMeter),
+            last_modified
+        );
+    }
+}
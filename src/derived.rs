@@ -0,0 +1,113 @@
+use std::{
+    fmt::{Debug, Display},
+    sync::Arc,
+};
+
+use crate::{StatData, Stats};
+
+/// A function that computes a derived stat from a [`Stats`] collection
+pub type DeriveFn = dyn Fn(&Stats) -> Box<dyn StatData> + Send + Sync;
+
+/// A stat that is recalculated from the other stats in a [`Stats`] collection.
+///
+/// Registered with [`Stats::register_derived`] or [`Stats::register_derived_with_dependencies`] and updated by
+/// [`Stats::recompute_derived`]
+#[derive(Clone)]
+pub struct DerivedStat {
+    pub(crate) stat_id: String,
+    pub(crate) dependencies: Vec<String>,
+    pub(crate) derive: Arc<DeriveFn>,
+}
+
+impl DerivedStat {
+    /// The id the derived value is stored under
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// The ids of the stats this derived stat reads, as declared when it was registered
+    pub fn dependencies(&self) -> &[String] {
+        &self.dependencies
+    }
+
+    /// Computes the derived value from the given stats
+    pub fn compute(&self, stats: &Stats) -> Box<dyn StatData> {
+        (self.derive)(stats)
+    }
+}
+
+impl Debug for DerivedStat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DerivedStat")
+            .field("stat_id", &self.stat_id)
+            .field("dependencies", &self.dependencies)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returned by [`Stats::recompute_derived`] when derived stats depend on each other in a cycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivedCycleError {
+    stat_ids: Vec<String>,
+}
+
+impl DerivedCycleError {
+    /// The ids of the derived stats that couldn't be ordered, sorted. Includes the stats in the cycle and any
+    /// derived stat that depends on them
+    pub fn stat_ids(&self) -> &[String] {
+        &self.stat_ids
+    }
+}
+
+impl Display for DerivedCycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "derived stats depend on each other in a cycle: {}",
+            self.stat_ids.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for DerivedCycleError {}
+
+/// Orders the derived stats so each one comes after every derived stat producing one of its dependencies.
+///
+/// Stats that don't depend on each other keep their registration order. Dependencies that aren't produced by a
+/// derived stat are ordinary stats and don't affect the order
+pub(crate) fn dependency_order(derived: &[DerivedStat]) -> Result<Vec<usize>, DerivedCycleError> {
+    // For every derived stat, the indices of the derived stats it has to wait for
+    let waiting_on: Vec<Vec<usize>> = derived
+        .iter()
+        .map(|derived_stat| {
+            derived
+                .iter()
+                .enumerate()
+                .filter(|(_, other)| derived_stat.dependencies.contains(&other.stat_id))
+                .map(|(index, _)| index)
+                .collect()
+        })
+        .collect();
+
+    let mut done = vec![false; derived.len()];
+    let mut order = Vec::with_capacity(derived.len());
+    while order.len() < derived.len() {
+        let next = (0..derived.len()).find(|&index| {
+            !done[index] && waiting_on[index].iter().all(|&dependency| done[dependency])
+        });
+        let Some(next) = next else {
+            let mut stat_ids: Vec<String> = derived
+                .iter()
+                .zip(done)
+                .filter(|(_, done)| !done)
+                .map(|(derived_stat, _)| derived_stat.stat_id.clone())
+                .collect();
+            stat_ids.sort_unstable();
+            stat_ids.dedup();
+            return Err(DerivedCycleError { stat_ids });
+        };
+        done[next] = true;
+        order.push(next);
+    }
+    Ok(order)
+}
@@ -0,0 +1,147 @@
+//! A [`StatData`] that stores categorical state such as the variant of an enum.
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// Stores the discriminant of an enum like value, such as the currently equipped weapon.
+///
+/// Arbitrary enums can't implement [`StatData`] through a blanket impl, so store their discriminant instead and convert
+/// back with [`DiscriminantStat::get`]. Set the stat to change the variant, or step through the variants in order:
+///
+/// - `add` steps forward by a `u32` or another [`DiscriminantStat`]'s discriminant, wrapping after the last variant
+/// - `sub` steps backward the same way, wrapping before the first variant
+/// - `default` is the first variant
+///
+/// ```
+/// # use bevy_easy_stats::discriminant::DiscriminantStat;
+/// #[derive(Debug, PartialEq)]
+/// enum Weapon {
+///     Sword,
+///     Bow,
+/// }
+///
+/// impl TryFrom<u32> for Weapon {
+///     type Error = ();
+///
+///     fn try_from(value: u32) -> Result<Self, ()> {
+///         match value {
+///             0 => Ok(Weapon::Sword),
+///             1 => Ok(Weapon::Bow),
+///             _ => Err(()),
+///         }
+///     }
+/// }
+///
+/// let weapon = DiscriminantStat::new(Weapon::Bow as u32, 2);
+/// assert_eq!(weapon.get::<Weapon>(), Some(Weapon::Bow));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct DiscriminantStat {
+    discriminant: u32,
+    variants: u32,
+}
+
+impl DiscriminantStat {
+    /// Creates a new [`DiscriminantStat`] for a type with the given number of variants.
+    ///
+    /// `variants` is clamped to at least 1 and the discriminant wraps into range
+    pub fn new(discriminant: u32, variants: u32) -> Self {
+        let variants = variants.max(1);
+        Self {
+            discriminant: discriminant % variants,
+            variants,
+        }
+    }
+
+    /// Returns the stored discriminant
+    pub fn discriminant(&self) -> u32 {
+        self.discriminant
+    }
+
+    /// Returns the number of variants
+    pub fn variants(&self) -> u32 {
+        self.variants
+    }
+
+    /// Sets the stored discriminant, wrapping it into range
+    pub fn set(&mut self, discriminant: u32) {
+        self.discriminant = discriminant % self.variants;
+    }
+
+    /// Converts the stored discriminant back into the given type
+    pub fn get<T: TryFrom<u32>>(&self) -> Option<T> {
+        T::try_from(self.discriminant).ok()
+    }
+
+    fn step(&mut self, steps: u32, forward: bool) {
+        let steps = steps % self.variants;
+        if forward {
+            self.discriminant = (self.discriminant + steps) % self.variants;
+        } else {
+            self.discriminant = (self.discriminant + self.variants - steps) % self.variants;
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for DiscriminantStat {
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(DiscriminantStat::new(0, self.variants))
+    }
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<DiscriminantStat>() {
+            self.step(other.discriminant, true);
+        } else if let Some(other) = other.downcast_ref::<u32>() {
+            self.step(*other, true);
+        }
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<DiscriminantStat>() {
+            self.step(other.discriminant, false);
+        } else if let Some(other) = other.downcast_ref::<u32>() {
+            self.step(*other, false);
+        }
+    }
+
+    fn eq_dyn(&self, other: &dyn StatData) -> bool {
+        other.downcast_ref::<DiscriminantStat>() == Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::CurrentWeapon, Stats};
+
+    use super::*;
+
+    #[test]
+    fn discriminant_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&CurrentWeapon, Box::new(DiscriminantStat::new(1, 3)));
+
+        stats.add_to_stat(&CurrentWeapon, Box::new(1u32));
+        let weapon = stats.get_stat_downcast::<DiscriminantStat>(&CurrentWeapon);
+        assert_eq!(weapon.map(|weapon| weapon.discriminant()), Some(2));
+
+        stats.add_to_stat(&CurrentWeapon, Box::new(1u32));
+        stats.sub_from_stat(&CurrentWeapon, Box::new(2u32));
+        let weapon = stats.get_stat_downcast::<DiscriminantStat>(&CurrentWeapon);
+        assert_eq!(weapon.map(|weapon| weapon.discriminant()), Some(1));
+
+        stats.reset_stat(&CurrentWeapon);
+        assert_eq!(
+            stats.get_stat_downcast::<DiscriminantStat>(&CurrentWeapon),
+            Some(&DiscriminantStat::new(0, 3))
+        );
+
+        stats.set_stat(&CurrentWeapon, Box::new('b'));
+        stats.add_to_stat(&CurrentWeapon, Box::new('c'));
+        assert_eq!(stats.get_stat_downcast::<char>(&CurrentWeapon), Some(&'b'));
+    }
+}
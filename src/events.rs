@@ -1,47 +1,241 @@
 use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use std::{path::PathBuf, time::Duration};
 
 use bevy::{
     app::{App, PostUpdate},
-    prelude::{on_event, Event, EventReader, IntoSystemConfigs, ResMut, Resource},
+    prelude::{
+        on_event, Component, DetectChangesMut, Entity, Event, EventReader, EventWriter, Events,
+        IntoSystemConfigs, Mut, Query, ResMut, Resource, World,
+    },
+    utils::HashMap,
 };
 
-use crate::{stat_modification::ModificationType, StatData, StatIdentifier, StatSystemSets, Stats};
+#[cfg(feature = "warn_on_missing")]
+use bevy::log::warn;
+
+#[cfg(feature = "serde")]
+use crate::bundle::SaveBundle;
+
+use crate::{
+    global::GlobalStats,
+    modification_log::{LoggedModification, ModificationLog},
+    stat_modification::{ModificationKind, ModificationType},
+    threshold::ThresholdDirection,
+    StatData, StatIdentifier, StatSystemSets, Stats,
+};
 
 pub trait StatAppExt {
-    /// Register a new stat resource, adds the [`ModifyStats`] event, and adds a system to automatically handle those events and update the stats on event.
-    fn register_stat_resource<
-        StatCollection: AsMut<Stats> + Send + Sync + 'static + Resource + Default,
+    /// Register a new stat resource, adds the [`ModifyStat`] and [`StatChanged`] events, and adds a system to automatically handle those events and update the stats on event.
+    fn register_stat_resource<StatCollection: crate::StatCollection + Resource + Default>(
+        &mut self,
+    );
+
+    /// Register a stat resource using the instance returned by `init`, for resources without a [`Default`] or that
+    /// start pre-populated.
+    ///
+    /// Replaces the resource if it already exists, otherwise behaves like [`StatAppExt::register_stat_resource`]
+    fn register_stat_resource_with<StatCollection: crate::StatCollection + Resource>(
+        &mut self,
+        init: impl FnOnce() -> StatCollection,
+    );
+
+    /// Registers the built in [`GlobalStats`] resource, see [`StatAppExt::register_stat_resource`]
+    fn register_global_stats(&mut self);
+
+    /// Records every [`ModifyStat`] event applied to the given stat resource in a [`ModificationLog`] keeping at most `max_len` entries
+    fn enable_modification_log<StatCollection: crate::StatCollection + Resource>(
+        &mut self,
+        max_len: usize,
+    );
+
+    /// Register a stat component, adds the [`ModifyEntityStat`] event and a system to automatically apply those events to the targeted entities.
+    ///
+    /// Events targeting entities that don't exist or don't have the component are skipped, the same as the stat
+    /// commands in [`StatEntityCommandsExt`](crate::StatEntityCommandsExt), so despawned entities need no cleanup.
+    /// Both only mark the component as changed when a modification changed one of its stats, see
+    /// [`Stats::apply_modification_manual`], so `Changed<StatCollection>` can be queried after
+    /// [`StatSystemSets::ApplyModifications`] or once the commands are applied.
+    fn register_stat_component<StatCollection: crate::StatCollection + Component>(&mut self);
+
+    /// Registers a threshold on a stat in the given stat resource, sending a [`StatThresholdCrossed`](crate::threshold::StatThresholdCrossed) event when the stat crosses it in the given direction.
+    ///
+    /// Stats are checked after [`StatSystemSets::ApplyModifications`] against their value from the previous check,
+    /// a stat that doesn't exist or isn't of type `T` counts as `T::default()`. The event is sent once per crossing
+    /// and a modification that crosses several thresholds sends an event for each of them in the order they were passed.
+    fn register_stat_threshold<
+        StatCollection: AsRef<Stats> + Send + Sync + 'static + Resource,
+        T: StatData + PartialOrd + Copy + Default,
     >(
         &mut self,
+        stat_id: impl StatIdentifier,
+        value: T,
+        direction: ThresholdDirection,
     );
+
+    /// Registers a stat in the given stat resource that loses `rate` every second until it reaches `floor`, see
+    /// [`DecayStat`](crate::decay::DecayStat).
+    ///
+    /// Decay is applied in [`StatSystemSets::BeforeModifications`] so modifications sent during the frame are
+    /// applied on top of the decayed value. Decay can be paused with [`StatDecays`](crate::decay::StatDecays)
+    fn register_stat_decay<StatCollection: crate::StatCollection + Resource>(
+        &mut self,
+        stat_id: impl StatIdentifier,
+        rate: f64,
+        floor: f64,
+    );
+
+    /// Registers [`Stats`] and the built in [`StatData`] implementations with the type registry so they can be inspected through reflection
+    #[cfg(feature = "reflect")]
+    fn register_stat_types(&mut self);
+
+    /// Loads the stat resource from the json file at the given path and saves it back to that file every interval.
+    ///
+    /// If the file doesn't exist yet the resource starts empty. A file that can't be loaded is moved to the same path
+    /// with `.bak` appended before anything is saved, and autosave is disabled if that fails or a backup already
+    /// exists, so a bad save is never overwritten. Saving serializes the stats during `PostUpdate` and writes the file
+    /// on the [`IoTaskPool`] so the schedule isn't blocked. Each save is written to a temporary file and renamed into
+    /// place, one at a time, so the file always holds a complete save. Can be combined with
+    /// [`StatAppExt::register_stat_resource`] in either order.
+    ///
+    /// [`IoTaskPool`]: bevy::tasks::IoTaskPool
+    #[cfg(feature = "serde")]
+    fn add_stat_autosave<StatCollection: crate::StatCollection + Resource + Default>(
+        &mut self,
+        path: impl Into<PathBuf>,
+        interval: Duration,
+    );
+
+    /// Clones the stats of every resource registered with [`StatAppExt::register_stat_resource`] into a single
+    /// [`SaveBundle`] keyed by type name, see [`SaveBundle::save`]
+    #[cfg(feature = "serde")]
+    fn save_all_stats(&self) -> SaveBundle;
+
+    /// Merges every collection in the [`SaveBundle`] back into the registered resource with the same type name, see
+    /// [`SaveBundle::load`]
+    #[cfg(feature = "serde")]
+    fn load_all_stats(&mut self, bundle: SaveBundle);
 }
 
 impl StatAppExt for App {
-    fn register_stat_resource<
-        StatCollection: AsMut<Stats> + Send + Sync + 'static + Resource + Default,
-    >(
+    fn register_stat_resource<StatCollection: crate::StatCollection + Resource + Default>(
         &mut self,
     ) {
-        self.add_event::<ModifyStat<StatCollection>>();
         self.init_resource::<StatCollection>();
+        add_stat_resource_handling::<StatCollection>(self);
+    }
+
+    fn register_stat_resource_with<StatCollection: crate::StatCollection + Resource>(
+        &mut self,
+        init: impl FnOnce() -> StatCollection,
+    ) {
+        self.insert_resource(init());
+        add_stat_resource_handling::<StatCollection>(self);
+    }
+
+    fn register_global_stats(&mut self) {
+        self.register_stat_resource::<GlobalStats>();
+    }
+
+    fn enable_modification_log<StatCollection: crate::StatCollection + Resource>(
+        &mut self,
+        max_len: usize,
+    ) {
+        self.insert_resource(ModificationLog::<StatCollection>::new(max_len));
+    }
+
+    fn register_stat_component<StatCollection: crate::StatCollection + Component>(&mut self) {
+        crate::configure_stat_system_sets(self);
+        self.add_event::<ModifyEntityStat<StatCollection>>();
         self.add_systems(
             PostUpdate,
-            handle_stat_modifications::<StatCollection>
-                .run_if(on_event::<ModifyStat<StatCollection>>)
+            handle_entity_stat_modifications::<StatCollection>
+                .run_if(on_event::<ModifyEntityStat<StatCollection>>)
                 .in_set(StatSystemSets::ApplyModifications),
         );
     }
+
+    fn register_stat_threshold<
+        StatCollection: AsRef<Stats> + Send + Sync + 'static + Resource,
+        T: StatData + PartialOrd + Copy + Default,
+    >(
+        &mut self,
+        stat_id: impl StatIdentifier,
+        value: T,
+        direction: ThresholdDirection,
+    ) {
+        crate::threshold::register_stat_threshold::<StatCollection, T>(
+            self,
+            stat_id.identifier().to_string(),
+            value,
+            direction,
+        );
+    }
+
+    fn register_stat_decay<StatCollection: crate::StatCollection + Resource>(
+        &mut self,
+        stat_id: impl StatIdentifier,
+        rate: f64,
+        floor: f64,
+    ) {
+        crate::decay::register_stat_decay::<StatCollection>(
+            self,
+            stat_id.identifier().to_string(),
+            rate,
+            floor,
+        );
+    }
+
+    #[cfg(feature = "reflect")]
+    fn register_stat_types(&mut self) {
+        self.register_type::<Stats>();
+        crate::implementations::register_stat_types(self);
+    }
+
+    #[cfg(feature = "serde")]
+    fn add_stat_autosave<StatCollection: crate::StatCollection + Resource + Default>(
+        &mut self,
+        path: impl Into<PathBuf>,
+        interval: Duration,
+    ) {
+        crate::persistence::add_stat_autosave::<StatCollection>(self, path.into(), interval);
+    }
+
+    #[cfg(feature = "serde")]
+    fn save_all_stats(&self) -> SaveBundle {
+        SaveBundle::save(self.world())
+    }
+
+    #[cfg(feature = "serde")]
+    fn load_all_stats(&mut self, bundle: SaveBundle) {
+        bundle.load(self.world_mut());
+    }
+}
+
+/// Adds the events and systems that apply modifications to a stat resource
+fn add_stat_resource_handling<StatCollection: crate::StatCollection + Resource>(app: &mut App) {
+    crate::configure_stat_system_sets(app);
+    app.add_event::<ModifyStat<StatCollection>>();
+    app.add_event::<StatChanged<StatCollection>>();
+    #[cfg(feature = "serde")]
+    crate::bundle::register_stat_resource::<StatCollection>(app);
+    app.add_systems(
+        PostUpdate,
+        handle_stat_modifications::<StatCollection>
+            .run_if(on_event::<ModifyStat<StatCollection>>)
+            .in_set(StatSystemSets::ApplyModifications),
+    );
 }
 
 /// An event that modifies a stat in a resource
 #[derive(Event)]
-pub struct ModifyStat<StatCollection: AsMut<Stats>> {
+pub struct ModifyStat<StatCollection: crate::StatCollection> {
     stat_id: Box<dyn StatIdentifier + 'static + Send + Sync>,
     modification_type: ModificationType,
     pd: PhantomData<StatCollection>,
 }
 
-impl<StatCollection: AsMut<Stats>> ModifyStat<StatCollection> {
+impl<StatCollection: crate::StatCollection> ModifyStat<StatCollection> {
     /// Create a new event
     pub fn new(
         stat_id: impl StatIdentifier + 'static + Send + Sync,
@@ -107,61 +301,240 @@ impl<StatCollection: AsMut<Stats>> ModifyStat<StatCollection> {
             pd: PhantomData,
         }
     }
+
+    /// Create a new toggle event
+    pub fn toggle(stat_id: impl StatIdentifier + 'static + Send + Sync) -> Self {
+        Self::new(stat_id, ModificationType::toggle())
+    }
+}
+
+/// An event that modifies a stat in the `StatCollection` component of an entity.
+///
+/// Every event sent during a frame is applied by a single system registered with
+/// [`StatAppExt::register_stat_component`], which looks up each targeted component once no matter how many events
+/// target it. Prefer these over the stat commands when many entities are updated every frame, since each command
+/// is applied with its own world access. Events targeting despawned entities are skipped
+#[derive(Event)]
+pub struct ModifyEntityStat<StatCollection: crate::StatCollection> {
+    entity: Entity,
+    stat_id: Box<dyn StatIdentifier + 'static + Send + Sync>,
+    modification_type: ModificationType,
+    pd: PhantomData<StatCollection>,
+}
+
+impl<StatCollection: crate::StatCollection> ModifyEntityStat<StatCollection> {
+    /// Create a new event
+    pub fn new(
+        entity: Entity,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        modification_type: ModificationType,
+    ) -> Self {
+        Self {
+            entity,
+            stat_id: Box::new(stat_id),
+            modification_type,
+            pd: PhantomData,
+        }
+    }
+
+    /// Create a new add event
+    pub fn add(
+        entity: Entity,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> Self {
+        Self::new(entity, stat_id, ModificationType::add(stat_data))
+    }
+
+    /// Create a new sub event
+    pub fn sub(
+        entity: Entity,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> Self {
+        Self::new(entity, stat_id, ModificationType::sub(stat_data))
+    }
+
+    /// Create a new set event
+    pub fn set(
+        entity: Entity,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> Self {
+        Self::new(entity, stat_id, ModificationType::set(stat_data))
+    }
+
+    /// Create a new remove event
+    pub fn remove(entity: Entity, stat_id: impl StatIdentifier + 'static + Send + Sync) -> Self {
+        Self::new(entity, stat_id, ModificationType::remove())
+    }
+
+    /// Create a new reset event
+    pub fn reset(entity: Entity, stat_id: impl StatIdentifier + 'static + Send + Sync) -> Self {
+        Self::new(entity, stat_id, ModificationType::reset())
+    }
+
+    /// Create a new toggle event
+    pub fn toggle(entity: Entity, stat_id: impl StatIdentifier + 'static + Send + Sync) -> Self {
+        Self::new(entity, stat_id, ModificationType::toggle())
+    }
+
+    /// The entity whose stats are modified
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+}
+
+/// An event sent after a [`ModifyStat`] event has changed a stat in a resource.
+///
+/// Modifications that leave the stat unchanged, such as a sub of a mismatched type or a [`ModificationType::SetMax`]
+/// that loses, don't send one. See [`Stats::apply_modification_manual`]
+#[derive(Event)]
+pub struct StatChanged<StatCollection: crate::StatCollection> {
+    stat_id: String,
+    kind: ModificationKind,
+    pd: PhantomData<StatCollection>,
 }
 
-fn handle_stat_modifications<StatCollection: AsMut<Stats> + Send + Sync + 'static + Resource>(
+impl<StatCollection: crate::StatCollection> StatChanged<StatCollection> {
+    /// The id of the stat that changed
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// The kind of modification that was applied, see [`ModificationType::reported_kind`]
+    pub fn kind(&self) -> ModificationKind {
+        self.kind
+    }
+}
+
+fn handle_stat_modifications<StatCollection: crate::StatCollection + Resource>(
     mut resource: ResMut<StatCollection>,
     mut event_reader: EventReader<ModifyStat<StatCollection>>,
+    mut changed_writer: EventWriter<StatChanged<StatCollection>>,
+    mut modification_log: Option<ResMut<ModificationLog<StatCollection>>>,
 ) {
-    let stats = resource.as_mut().as_mut();
+    let stats = resource.stats_mut();
     for event in event_reader.read() {
-        match &event.modification_type {
-            ModificationType::Add(data) => {
-                stats.add_to_stat_manual(event.stat_id.identifier(), data.clone())
-            }
-            ModificationType::Sub(data) => {
-                stats.sub_from_stat_manual(event.stat_id.identifier(), data.clone())
-            }
-            ModificationType::Remove => stats.remove_stat_manual(event.stat_id.identifier()),
-            ModificationType::Set(data) => {
-                stats.set_stat_manual(event.stat_id.identifier(), data.clone())
-            }
-            ModificationType::Reset => stats.reset_stat_manual(event.stat_id.identifier()),
+        if let Some(changed) =
+            apply_stat_modification(stats, event, modification_log.as_deref_mut())
+        {
+            changed_writer.send(changed);
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use bevy::{
-        app::{App, PostUpdate, PreUpdate},
-        prelude::{EventWriter, IntoSystemConfigs, Res, Resource},
-    };
-
-    use crate::{
-        events::{ModifyStat, StatAppExt},
-        StatIdentifier, StatSystemSets, Stats,
+/// Applies every [`ModifyStat`] event sent for the given stat resource immediately instead of waiting for
+/// [`StatSystemSets::ApplyModifications`] in [`PostUpdate`].
+///
+/// Use this when a system has to read the result of a modification in the same frame, either from an exclusive
+/// system or by queueing it with `commands.queue(apply_pending_stats::<ResourceStats>)`. Does nothing if the
+/// resource was never registered with [`StatAppExt::register_stat_resource`].
+///
+/// The pending events are consumed, so they are not applied a second time in [`PostUpdate`] and any other
+/// [`EventReader`] of [`ModifyStat`] that hasn't read them yet never will. [`StatChanged`] events and the
+/// [`ModificationLog`] are written at the time of the flush. A flush before [`PostUpdate`] applies the modifications
+/// before this frame's [`StatSystemSets::BeforeModifications`] systems such as stat decay run, while events sent
+/// after the flush are applied in [`PostUpdate`] as usual.
+pub fn apply_pending_stats<StatCollection: crate::StatCollection + Resource>(world: &mut World) {
+    let Some(mut events) = world.get_resource_mut::<Events<ModifyStat<StatCollection>>>() else {
+        return;
     };
+    let events: Vec<ModifyStat<StatCollection>> = events.drain().collect();
+    if events.is_empty() {
+        return;
+    }
+    let mut changed = Vec::with_capacity(events.len());
+    world.resource_scope(|world, mut resource: Mut<StatCollection>| {
+        let mut modification_log = world.get_resource_mut::<ModificationLog<StatCollection>>();
+        let stats = resource.stats_mut();
+        changed.extend(events.iter().filter_map(|event| {
+            apply_stat_modification(stats, event, modification_log.as_deref_mut())
+        }));
+    });
+    world.send_event_batch(changed);
+}
 
-    #[derive(Hash)]
-    pub struct EnemiesKilled;
-
-    impl StatIdentifier for EnemiesKilled {
-        fn identifier(&self) -> &'static str {
-            "Enemies Killed"
-        }
+/// Applies a single [`ModifyStat`] event, logging it if the log is enabled, and returns the matching [`StatChanged`]
+/// if the stat changed
+fn apply_stat_modification<StatCollection: crate::StatCollection + Resource>(
+    stats: &mut Stats,
+    event: &ModifyStat<StatCollection>,
+    modification_log: Option<&mut ModificationLog<StatCollection>>,
+) -> Option<StatChanged<StatCollection>> {
+    if let Some(modification_log) = modification_log {
+        modification_log.push(LoggedModification::new(
+            event.stat_id.identifier(),
+            &event.modification_type,
+        ));
     }
+    let changed = stats
+        .apply_modification_manual(event.stat_id.identifier(), event.modification_type.clone());
+    changed.then(|| StatChanged {
+        stat_id: event.stat_id.identifier().to_string(),
+        kind: event.modification_type.reported_kind(),
+        pd: PhantomData,
+    })
+}
 
-    #[derive(Resource, Default)]
-    pub struct ResourceStats {
-        stats: Stats,
+fn handle_entity_stat_modifications<StatCollection: crate::StatCollection + Component>(
+    mut query: Query<&mut StatCollection>,
+    mut event_reader: EventReader<ModifyEntityStat<StatCollection>>,
+) {
+    // Group the events by entity, keeping the order they were sent in, so each component is fetched once
+    let mut entity_indices = HashMap::<Entity, usize>::default();
+    let mut grouped: Vec<(Entity, Vec<&ModifyEntityStat<StatCollection>>)> = Vec::new();
+    for event in event_reader.read() {
+        let index = *entity_indices.entry(event.entity).or_insert_with(|| {
+            grouped.push((event.entity, Vec::new()));
+            grouped.len() - 1
+        });
+        grouped[index].1.push(event);
     }
 
-    impl AsMut<Stats> for ResourceStats {
-        fn as_mut(&mut self) -> &mut Stats {
-            &mut self.stats
+    for (entity, events) in grouped {
+        let Ok(mut stat_collection) = query.get_mut(entity) else {
+            #[cfg(feature = "warn_on_missing")]
+            warn!(
+                "Failed to modify stats: entity {} does not exist or does not have a {} component",
+                entity,
+                std::any::type_name::<StatCollection>()
+            );
+            continue;
+        };
+        // Only mark the component as changed if a modification changed a stat
+        let stats = stat_collection.bypass_change_detection().stats_mut();
+        let mut changed = false;
+        for event in events {
+            changed |= stats.apply_modification_manual(
+                event.stat_id.identifier(),
+                event.modification_type.clone(),
+            );
+        }
+        if changed {
+            stat_collection.set_changed();
         }
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        app::{App, PostUpdate, PreUpdate},
+        prelude::{
+            Changed, Entity, EventReader, EventWriter, Events, IntoSystemConfigs, Query, Res,
+            Resource,
+        },
+    };
+
+    use crate::{
+        events::{apply_pending_stats, ModifyEntityStat, ModifyStat, StatAppExt, StatChanged},
+        modification_log::ModificationLog,
+        stat_modification::ModificationKind,
+        stat_modification::ModificationType,
+        test_utils::{impl_test_stat_collection, EnemiesKilled, EntityStats, ResourceStats},
+        StatCommandsExt, StatSystemSets, Stats, StatsPlugin,
+    };
 
     #[test]
     fn resource_stats() {
@@ -192,4 +565,309 @@ mod tests {
         );
         app.run();
     }
+
+    #[test]
+    fn apply_pending_resource_stats() {
+        let mut app = App::new();
+        app.init_resource::<ResourceStats>();
+        app.register_stat_resource::<ResourceStats>();
+
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::add(EnemiesKilled, 2u64));
+        apply_pending_stats::<ResourceStats>(app.world_mut());
+        let enemies_killed = |app: &App| {
+            *app.world()
+                .resource::<ResourceStats>()
+                .stats
+                .get_stat_downcast::<u64>(&EnemiesKilled)
+                .unwrap()
+        };
+        assert_eq!(enemies_killed(&app), 2);
+        assert_eq!(
+            app.world()
+                .resource::<Events<StatChanged<ResourceStats>>>()
+                .len(),
+            1
+        );
+
+        // The flushed event isn't applied again
+        app.update();
+        assert_eq!(enemies_killed(&app), 2);
+    }
+
+    #[derive(Resource)]
+    pub struct SeededStats {
+        stats: Stats,
+    }
+
+    impl SeededStats {
+        fn new(starting_kills: u64) -> Self {
+            let mut stats = Stats::new();
+            stats.set_stat(&EnemiesKilled, Box::new(starting_kills));
+            Self { stats }
+        }
+    }
+
+    impl_test_stat_collection!(SeededStats);
+
+    #[test]
+    fn resource_stats_without_default() {
+        let mut app = App::new();
+        app.register_stat_resource_with(|| SeededStats::new(10));
+        app.add_systems(
+            PreUpdate,
+            |mut event_writer: EventWriter<ModifyStat<SeededStats>>| {
+                event_writer.send(ModifyStat::add(EnemiesKilled, 2u64));
+            },
+        );
+        app.update();
+
+        assert_eq!(
+            app.world()
+                .resource::<SeededStats>()
+                .stats
+                .get_stat_downcast::<u64>(&EnemiesKilled),
+            Some(&12u64)
+        );
+    }
+
+    #[derive(Resource, Default)]
+    pub struct OtherResourceStats {
+        stats: Stats,
+    }
+
+    impl_test_stat_collection!(OtherResourceStats);
+
+    #[test]
+    fn stats_plugin() {
+        let mut app = App::new();
+        app.register_stat_resource::<ResourceStats>();
+        app.add_plugins(StatsPlugin);
+        app.register_stat_resource::<OtherResourceStats>();
+        app.add_systems(
+            PreUpdate,
+            |mut resource_writer: EventWriter<ModifyStat<ResourceStats>>,
+             mut other_writer: EventWriter<ModifyStat<OtherResourceStats>>| {
+                resource_writer.send(ModifyStat::add(EnemiesKilled, 2u64));
+                other_writer.send(ModifyStat::add(EnemiesKilled, 3u64));
+            },
+        );
+        app.update();
+
+        assert_eq!(
+            *app.world()
+                .resource::<ResourceStats>()
+                .stats
+                .get_stat_downcast::<u64>(&EnemiesKilled)
+                .unwrap(),
+            2u64
+        );
+        assert_eq!(
+            *app.world()
+                .resource::<OtherResourceStats>()
+                .stats
+                .get_stat_downcast::<u64>(&EnemiesKilled)
+                .unwrap(),
+            3u64
+        );
+    }
+
+    #[test]
+    fn modification_anchor_sets() {
+        let mut app = App::new();
+        app.register_stat_resource::<ResourceStats>();
+        app.add_plugins(StatsPlugin);
+        app.register_stat_resource::<OtherResourceStats>();
+        app.add_systems(
+            PreUpdate,
+            |mut writer: EventWriter<ModifyStat<OtherResourceStats>>| {
+                writer.send(ModifyStat::add(EnemiesKilled, 3u64));
+            },
+        );
+        app.add_systems(
+            PostUpdate,
+            (
+                (|stats: Res<OtherResourceStats>| {
+                    assert!(!stats.stats.contains_stat(&EnemiesKilled));
+                })
+                .in_set(StatSystemSets::BeforeModifications),
+                (|stats: Res<OtherResourceStats>| {
+                    assert_eq!(
+                        stats.stats.get_stat_downcast::<u64>(&EnemiesKilled),
+                        Some(&3)
+                    );
+                })
+                .in_set(StatSystemSets::AfterModifications),
+            ),
+        );
+        app.update();
+    }
+
+    #[test]
+    fn stat_changed_events() {
+        let mut app = App::new();
+        app.register_stat_resource::<ResourceStats>();
+        app.add_systems(
+            PreUpdate,
+            |mut event_writer: EventWriter<ModifyStat<ResourceStats>>| {
+                event_writer.send(ModifyStat::add(EnemiesKilled, 2u64));
+                // Neither changes the stat, the types differ and 1 is less than 2
+                event_writer.send(ModifyStat::sub(EnemiesKilled, String::from("Slime")));
+                event_writer.send(ModifyStat::new(
+                    EnemiesKilled,
+                    ModificationType::set_max(1u64),
+                ));
+                event_writer.send(ModifyStat::new(
+                    EnemiesKilled,
+                    ModificationType::add_percent(-50.0),
+                ));
+                event_writer.send(ModifyStat::remove(EnemiesKilled));
+                event_writer.send(ModifyStat::remove(EnemiesKilled));
+                event_writer.send(ModifyStat::reset(EnemiesKilled));
+            },
+        );
+        app.add_systems(
+            PostUpdate,
+            (|mut event_reader: EventReader<StatChanged<ResourceStats>>| {
+                let changes = event_reader
+                    .read()
+                    .map(|event| (event.stat_id().to_string(), event.kind()))
+                    .collect::<Vec<(String, ModificationKind)>>();
+                assert_eq!(
+                    changes,
+                    vec![
+                        ("Enemies Killed".to_string(), ModificationKind::Add),
+                        ("Enemies Killed".to_string(), ModificationKind::SubPercent),
+                        ("Enemies Killed".to_string(), ModificationKind::Remove)
+                    ]
+                );
+            })
+            .after(StatSystemSets::ApplyModifications),
+        );
+        app.run();
+    }
+
+    #[test]
+    fn entity_stat_events() {
+        let mut app = App::new();
+        app.register_stat_component::<EntityStats>();
+        let entity = app.world_mut().spawn(EntityStats::default()).id();
+        let despawned = app.world_mut().spawn(EntityStats::default()).id();
+
+        app.world_mut()
+            .send_event(ModifyEntityStat::<EntityStats>::add(
+                entity,
+                EnemiesKilled,
+                2u64,
+            ));
+        app.world_mut()
+            .send_event(ModifyEntityStat::<EntityStats>::add(
+                despawned,
+                EnemiesKilled,
+                2u64,
+            ));
+        app.world_mut()
+            .send_event(ModifyEntityStat::<EntityStats>::add(
+                entity,
+                EnemiesKilled,
+                3u64,
+            ));
+        // Events for entities that no longer exist are skipped
+        app.world_mut().despawn(despawned);
+        app.update();
+
+        assert_eq!(
+            app.world()
+                .entity(entity)
+                .get::<EntityStats>()
+                .unwrap()
+                .stats
+                .get_stat_downcast::<u64>(&EnemiesKilled),
+            Some(&5u64)
+        );
+    }
+
+    #[test]
+    fn modification_log() {
+        let mut app = App::new();
+        app.register_stat_resource::<ResourceStats>();
+        app.enable_modification_log::<ResourceStats>(2);
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::add(EnemiesKilled, 1u64));
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::set(EnemiesKilled, 5u64));
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::reset(EnemiesKilled));
+        app.update();
+
+        let mut modification_log = app
+            .world_mut()
+            .resource_mut::<ModificationLog<ResourceStats>>();
+        let entries: Vec<_> = modification_log.drain().collect();
+        assert!(modification_log.is_empty());
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].kind(), ModificationKind::Set);
+        assert_eq!(
+            entries[0]
+                .data()
+                .and_then(|data| data.downcast_ref::<u64>()),
+            Some(&5u64)
+        );
+        assert_eq!(entries[1].kind(), ModificationKind::Reset);
+        assert!(entries[1].data().is_none());
+    }
+
+    #[test]
+    fn entity_stat_change_detection() {
+        let mut app = App::new();
+        app.register_stat_component::<EntityStats>();
+        let event_target = app.world_mut().spawn(EntityStats::default()).id();
+        let command_target = app.world_mut().spawn(EntityStats::default()).id();
+        let untouched = app.world_mut().spawn(EntityStats::default()).id();
+        let despawned = app.world_mut().spawn(EntityStats::default()).id();
+
+        let changed_system = app.register_system(
+            |query: Query<Entity, Changed<EntityStats>>| -> Vec<Entity> { query.iter().collect() },
+        );
+        app.world_mut().run_system(changed_system).unwrap();
+
+        app.world_mut()
+            .send_event(ModifyEntityStat::<EntityStats>::add(
+                event_target,
+                EnemiesKilled,
+                1u64,
+            ));
+        app.world_mut().commands().modify_stat::<EntityStats>(
+            command_target,
+            EnemiesKilled,
+            ModificationType::add(1u64),
+        );
+        // Neither changes a stat since the stat doesn't exist, and the despawned entity is skipped
+        app.world_mut()
+            .send_event(ModifyEntityStat::<EntityStats>::reset(
+                untouched,
+                EnemiesKilled,
+            ));
+        app.world_mut().commands().modify_stat::<EntityStats>(
+            untouched,
+            EnemiesKilled,
+            ModificationType::remove(),
+        );
+        app.world_mut().despawn(despawned);
+        app.world_mut()
+            .send_event(ModifyEntityStat::<EntityStats>::add(
+                despawned,
+                EnemiesKilled,
+                1u64,
+            ));
+        app.update();
+
+        let mut changed = app.world_mut().run_system(changed_system).unwrap();
+        changed.sort();
+        let mut expected = vec![event_target, command_target];
+        expected.sort();
+        assert_eq!(changed, expected);
+        assert!(!changed.contains(&untouched));
+        assert!(!changed.contains(&despawned));
+    }
 }
@@ -0,0 +1,147 @@
+//! A [`StatData`] wrapper that keeps a numeric stat from dropping below a floor.
+
+use std::time::Duration;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::{bounded::wrapped_numeric_stat_ops, StatData};
+
+/// A numeric stat that is raised back to `floor` whenever a modification would take it lower.
+///
+/// Useful for signed stats that must not go negative, such as a currency stored signed for arithmetic convenience.
+/// Unlike saturating subtraction, which stops at the minimum value of the type, the floor can be any value. Use
+/// [`BoundedStat`](crate::bounded::BoundedStat) to limit the stat from above as well.
+///
+/// Adding or subtracting either another [`FloorStat`] or the plain numeric type is supported, the floor of the
+/// stored stat is always the one that is kept. Resetting the stat keeps the floor and sets the value to zero, or to
+/// the floor if it is above zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct FloorStat<T> {
+    value: T,
+    floor: T,
+}
+
+impl<T: PartialOrd + Copy> FloorStat<T> {
+    /// Creates a new [`FloorStat`], raising the given value to the floor if it is below it
+    pub fn new(value: T, floor: T) -> Self {
+        let mut stat = Self { value, floor };
+        stat.apply_floor();
+        stat
+    }
+
+    /// Returns the current value
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the lowest value the stat can hold
+    pub fn floor(&self) -> T {
+        self.floor
+    }
+
+    /// Sets the value, raising it to the floor if it is below it
+    pub fn set(&mut self, value: T) {
+        self.value = value;
+        self.apply_floor();
+    }
+
+    fn apply_floor(&mut self) {
+        if self.value < self.floor {
+            self.value = self.floor;
+        }
+    }
+}
+
+macro_rules! impl_floor_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for FloorStat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(FloorStat::new(<$ty as Default>::default(), self.floor))
+                }
+
+                wrapped_numeric_stat_ops!(FloorStat<$ty>, $ty, value, apply_floor);
+            }
+        )*
+    };
+}
+
+impl_floor_stat!(
+    Duration => "FloorStat<Duration>",
+    u128 => "FloorStat<u128>",
+    u64 => "FloorStat<u64>",
+    u32 => "FloorStat<u32>",
+    u16 => "FloorStat<u16>",
+    u8 => "FloorStat<u8>",
+    f64 => "FloorStat<f64>",
+    f32 => "FloorStat<f32>",
+    i128 => "FloorStat<i128>",
+    i64 => "FloorStat<i64>",
+    i32 => "FloorStat<i32>",
+    i16 => "FloorStat<i16>",
+    i8 => "FloorStat<i8>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::Gold, Stats};
+
+    use super::*;
+
+    #[test]
+    fn floor_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Gold, Box::new(FloorStat::new(30i64, 0)));
+
+        stats.sub_from_stat(&Gold, Box::new(50i64));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<FloorStat<i64>>(&Gold)
+                .unwrap()
+                .value(),
+            0
+        );
+
+        stats.add_to_stat(&Gold, Box::new(FloorStat::new(15i64, -100)));
+        stats.apply_percent(&Gold, -200.0);
+        assert_eq!(
+            *stats.get_stat_downcast::<FloorStat<i64>>(&Gold).unwrap(),
+            FloorStat::new(0i64, 0)
+        );
+
+        stats.set_stat(&Gold, Box::new(FloorStat::new(40i64, 10)));
+        stats.reset_stat(&Gold);
+        assert_eq!(
+            stats
+                .get_stat_downcast::<FloorStat<i64>>(&Gold)
+                .unwrap()
+                .value(),
+            10
+        );
+    }
+
+    #[test]
+    fn floor_duration_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(
+            &Gold,
+            Box::new(FloorStat::new(
+                Duration::from_secs(5),
+                Duration::from_secs(2),
+            )),
+        );
+
+        stats.sub_from_stat(&Gold, Box::new(Duration::from_secs(30)));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<FloorStat<Duration>>(&Gold)
+                .unwrap()
+                .value(),
+            Duration::from_secs(2)
+        );
+    }
+}
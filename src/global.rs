@@ -0,0 +1,203 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::{EventWriter, Res, Resource},
+};
+
+use crate::{
+    events::ModifyStat, stat_modification::ModificationType, StatData, StatIdentifier, Stats,
+};
+
+/// A built in stat resource for games that only need a single global stats collection.
+///
+/// Register it with [`StatAppExt::register_global_stats`](crate::StatAppExt::register_global_stats) and access it
+/// with [`StatReader`] and [`StatWriter`]
+#[derive(Resource, Debug, Default)]
+pub struct GlobalStats(pub Stats);
+
+impl AsMut<Stats> for GlobalStats {
+    fn as_mut(&mut self) -> &mut Stats {
+        &mut self.0
+    }
+}
+
+impl AsRef<Stats> for GlobalStats {
+    fn as_ref(&self) -> &Stats {
+        &self.0
+    }
+}
+
+/// A [`SystemParam`] to read the [`GlobalStats`] or any other stat resource.
+///
+/// Only shared access to the resource is taken and it only has to implement `AsRef<Stats>`, so systems that read
+/// stats, such as UI systems, can run in parallel with each other
+///
+/// ```ignore
+/// fn update_gold_text(stats: StatReader<PlayerStats>, mut text: Single<&mut Text, With<GoldText>>) {
+///     if let Some(gold) = stats.get::<u64>(&Gold) {
+///         text.0 = gold.to_string();
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct StatReader<'w, StatCollection: AsRef<Stats> + Resource = GlobalStats> {
+    stats: Res<'w, StatCollection>,
+}
+
+impl<StatCollection: AsRef<Stats> + Resource> StatReader<'_, StatCollection> {
+    /// Returns the read [`Stats`]
+    pub fn stats(&self) -> &Stats {
+        self.stats.as_ref().as_ref()
+    }
+
+    /// Gets the [`StatData`] for the requested [`StatIdentifier`].
+    #[allow(clippy::borrowed_box)]
+    pub fn get_stat(&self, stat_id: &impl StatIdentifier) -> Option<&Box<dyn StatData>> {
+        self.stats().get_stat(stat_id)
+    }
+
+    /// Gets the [`StatData`] for the requested [`StatIdentifier`] and attempts to downcast it into the given type
+    pub fn get_stat_downcast<Stat: StatData + 'static>(
+        &self,
+        stat_id: &impl StatIdentifier,
+    ) -> Option<&Stat> {
+        self.stats().get_stat_downcast::<Stat>(stat_id)
+    }
+
+    /// Gets the requested [`StatIdentifier`] downcast into the given type, see [`StatReader::get_stat_downcast`]
+    pub fn get<Stat: StatData + 'static>(&self, stat_id: &impl StatIdentifier) -> Option<&Stat> {
+        self.get_stat_downcast::<Stat>(stat_id)
+    }
+
+    /// Returns true if the requested [`StatIdentifier`] exists in the read [`Stats`]
+    pub fn contains_stat(&self, stat_id: &impl StatIdentifier) -> bool {
+        self.stats().contains_stat(stat_id)
+    }
+}
+
+/// A [`SystemParam`] to modify the [`GlobalStats`].
+///
+/// Modifications are sent as [`ModifyStat`] events and applied in [`StatSystemSets::ApplyModifications`](crate::StatSystemSets::ApplyModifications)
+#[derive(SystemParam)]
+pub struct StatWriter<'w> {
+    events: EventWriter<'w, ModifyStat<GlobalStats>>,
+}
+
+impl StatWriter<'_> {
+    /// Send a modification to the targeted [`StatIdentifier`]
+    pub fn modify(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        modification_type: ModificationType,
+    ) -> &mut Self {
+        self.events
+            .send(ModifyStat::new(stat_id, modification_type));
+        self
+    }
+
+    /// Send an add with the given [`StatData`] to the targeted [`StatIdentifier`]
+    pub fn add(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> &mut Self {
+        self.events.send(ModifyStat::add(stat_id, stat_data));
+        self
+    }
+
+    /// Send a sub with the given [`StatData`] to the targeted [`StatIdentifier`]
+    pub fn sub(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> &mut Self {
+        self.events.send(ModifyStat::sub(stat_id, stat_data));
+        self
+    }
+
+    /// Send a set with the given [`StatData`] to the targeted [`StatIdentifier`]
+    pub fn set(
+        &mut self,
+        stat_id: impl StatIdentifier + 'static + Send + Sync,
+        stat_data: impl StatData,
+    ) -> &mut Self {
+        self.events.send(ModifyStat::set(stat_id, stat_data));
+        self
+    }
+
+    /// Send a remove to the targeted [`StatIdentifier`]
+    pub fn remove(&mut self, stat_id: impl StatIdentifier + 'static + Send + Sync) -> &mut Self {
+        self.events.send(ModifyStat::remove(stat_id));
+        self
+    }
+
+    /// Send a reset to the targeted [`StatIdentifier`]
+    pub fn reset(&mut self, stat_id: impl StatIdentifier + 'static + Send + Sync) -> &mut Self {
+        self.events.send(ModifyStat::reset(stat_id));
+        self
+    }
+
+    /// Send a toggle to the targeted [`StatIdentifier`]
+    pub fn toggle(&mut self, stat_id: impl StatIdentifier + 'static + Send + Sync) -> &mut Self {
+        self.events.send(ModifyStat::toggle(stat_id));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        app::{App, PostUpdate, PreUpdate},
+        prelude::IntoSystemConfigs,
+    };
+
+    use crate::{test_utils::EnemiesKilled, StatAppExt, StatSystemSets};
+
+    use super::*;
+
+    #[test]
+    fn global_stats() {
+        let mut app = App::new();
+        app.register_global_stats();
+        app.add_systems(PreUpdate, |mut writer: StatWriter| {
+            writer.add(EnemiesKilled, 2u64).add(EnemiesKilled, 3u64);
+        });
+        app.add_systems(
+            PostUpdate,
+            (|reader: StatReader| {
+                assert_eq!(reader.get_stat_downcast::<u64>(&EnemiesKilled), Some(&5u64));
+            })
+            .after(StatSystemSets::ApplyModifications),
+        );
+        app.run();
+    }
+
+    #[derive(Resource, Default)]
+    pub struct ReadOnlyStats(Stats);
+
+    impl AsRef<Stats> for ReadOnlyStats {
+        fn as_ref(&self) -> &Stats {
+            &self.0
+        }
+    }
+
+    #[test]
+    fn read_only_stat_reader() {
+        let mut app = App::new();
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(3u64));
+        app.insert_resource(ReadOnlyStats(stats));
+        app.add_systems(
+            PostUpdate,
+            (
+                |reader: StatReader<ReadOnlyStats>| {
+                    assert_eq!(reader.get_stat_downcast::<u64>(&EnemiesKilled), Some(&3u64));
+                },
+                |reader: StatReader<ReadOnlyStats>| {
+                    assert!(reader.contains_stat(&EnemiesKilled));
+                    assert_eq!(reader.get::<u64>(&EnemiesKilled), Some(&3u64));
+                },
+            ),
+        );
+        app.run();
+    }
+}
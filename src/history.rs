@@ -0,0 +1,153 @@
+//! A [`StatData`] wrapper that keeps the most recent values a numeric stat has held.
+
+use std::{collections::VecDeque, time::Duration};
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// A numeric stat that remembers the last `capacity` values it held, such as for drawing a sparkline.
+///
+/// Adding or subtracting either another [`HistoryStat`] or the plain numeric type is supported, and the resulting
+/// value is pushed onto the history after every change, dropping the oldest value once the history is full. The
+/// value the stat was created with is not part of the history. Resetting the stat sets the current value to zero and
+/// clears the history, keeping the capacity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct HistoryStat<T> {
+    current: T,
+    history: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T: Copy> HistoryStat<T> {
+    /// Creates a new [`HistoryStat`] at the given value that remembers up to `capacity` values
+    pub fn new(value: T, capacity: usize) -> Self {
+        Self {
+            current: value,
+            history: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Returns the current value
+    pub fn current(&self) -> T {
+        self.current
+    }
+
+    /// Returns the recorded values, oldest first. The newest value is the current one
+    pub fn history(&self) -> &VecDeque<T> {
+        &self.history
+    }
+
+    /// Returns the maximum number of values kept in the history
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the current value, recording it in the history
+    pub fn set(&mut self, value: T) {
+        self.current = value;
+        self.record();
+    }
+
+    fn record(&mut self) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.history.len() >= self.capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.current);
+    }
+}
+
+macro_rules! impl_history_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for HistoryStat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(HistoryStat::new(<$ty as Default>::default(), self.capacity))
+                }
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<HistoryStat<$ty>>() {
+                        other.current
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    StatData::add(&mut self.current, Box::new(other));
+                    self.record();
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<HistoryStat<$ty>>() {
+                        other.current
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    StatData::sub(&mut self.current, Box::new(other));
+                    self.record();
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    StatData::apply_percent(&mut self.current, percent);
+                    self.record();
+                }
+            }
+        )*
+    };
+}
+
+impl_history_stat!(
+    Duration => "HistoryStat<Duration>",
+    u128 => "HistoryStat<u128>",
+    u64 => "HistoryStat<u64>",
+    u32 => "HistoryStat<u32>",
+    u16 => "HistoryStat<u16>",
+    u8 => "HistoryStat<u8>",
+    f64 => "HistoryStat<f64>",
+    f32 => "HistoryStat<f32>",
+    i128 => "HistoryStat<i128>",
+    i64 => "HistoryStat<i64>",
+    i32 => "HistoryStat<i32>",
+    i16 => "HistoryStat<i16>",
+    i8 => "HistoryStat<i8>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::FrameTime, Stats};
+
+    use super::*;
+
+    #[test]
+    fn history_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&FrameTime, Box::new(HistoryStat::new(10u32, 3)));
+
+        stats.add_to_stat(&FrameTime, Box::new(5u32));
+        stats.sub_from_stat(&FrameTime, Box::new(3u32));
+        stats.add_to_stat(&FrameTime, Box::new(HistoryStat::new(8u32, 3)));
+        stats.apply_percent(&FrameTime, 50.0);
+
+        let frame_time = stats
+            .get_stat_downcast::<HistoryStat<u32>>(&FrameTime)
+            .unwrap();
+        assert_eq!(frame_time.current(), 30);
+        assert_eq!(frame_time.history(), &VecDeque::from([12, 20, 30]));
+
+        stats.reset_stat(&FrameTime);
+        assert_eq!(
+            stats.get_stat_downcast::<HistoryStat<u32>>(&FrameTime),
+            Some(&HistoryStat::new(0u32, 3))
+        );
+    }
+}
@@ -1,22 +1,399 @@
-use std::time::Duration;
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+    num::{NonZeroU32, NonZeroU64},
+    time::Duration,
+};
 
+#[cfg(feature = "reflect")]
+use bevy::app::App;
+use bevy::{
+    ecs::entity::Entity,
+    math::{IVec2, IVec3, Vec2, Vec3},
+    time::Timer,
+};
+
+#[cfg(feature = "reflect")]
+use crate::{
+    approx::ApproxFloat, arith::ArithStat, average::MovingAverageStat, bounded::BoundedStat,
+    counter::CounterStat, discriminant::DiscriminantStat, floor::FloorStat, history::HistoryStat,
+    list::ListStat, modifier::ModifierStack, numeric::Numeric, peak::PeakTrackedStat,
+    ReflectStatData,
+};
 use crate::StatData;
 
+/// Registers every built in [`StatData`] implementation along with its [`ReflectStatData`] type data
+#[cfg(feature = "reflect")]
+pub(crate) fn register_stat_types(app: &mut App) {
+    macro_rules! register {
+        ($($ty:ty),*) => {
+            $(
+                app.register_type::<$ty>();
+                app.register_type_data::<$ty, ReflectStatData>();
+            )*
+        };
+    }
+
+    register!(
+        Duration, Timer, Entity, char, bool, String, u128, u64, u32, u16, u8, f64, f32, i128, i64,
+        i32, i16, i8
+    );
+    register!(Vec2, Vec3, IVec2, IVec3);
+    register!(CounterStat);
+    register!(NonZeroU32, NonZeroU64);
+    register!(HashMap<String, u64>, HashMap<String, f64>);
+    register!(
+        (Duration, Duration),
+        (u128, u128),
+        (u64, u64),
+        (u32, u32),
+        (u16, u16),
+        (u8, u8),
+        (f64, f64),
+        (f32, f32),
+        (i128, i128),
+        (i64, i64),
+        (i32, i32),
+        (i16, i16),
+        (i8, i8)
+    );
+    register!(
+        Option<Duration>,
+        Option<bool>,
+        Option<String>,
+        Option<u128>,
+        Option<u64>,
+        Option<u32>,
+        Option<u16>,
+        Option<u8>,
+        Option<f64>,
+        Option<f32>,
+        Option<i128>,
+        Option<i64>,
+        Option<i32>,
+        Option<i16>,
+        Option<i8>
+    );
+    register!(
+        BoundedStat<Duration>,
+        BoundedStat<u128>,
+        BoundedStat<u64>,
+        BoundedStat<u32>,
+        BoundedStat<u16>,
+        BoundedStat<u8>,
+        BoundedStat<f64>,
+        BoundedStat<f32>,
+        BoundedStat<i128>,
+        BoundedStat<i64>,
+        BoundedStat<i32>,
+        BoundedStat<i16>,
+        BoundedStat<i8>
+    );
+    register!(
+        FloorStat<Duration>,
+        FloorStat<u128>,
+        FloorStat<u64>,
+        FloorStat<u32>,
+        FloorStat<u16>,
+        FloorStat<u8>,
+        FloorStat<f64>,
+        FloorStat<f32>,
+        FloorStat<i128>,
+        FloorStat<i64>,
+        FloorStat<i32>,
+        FloorStat<i16>,
+        FloorStat<i8>
+    );
+    register!(
+        PeakTrackedStat<Duration>,
+        PeakTrackedStat<u128>,
+        PeakTrackedStat<u64>,
+        PeakTrackedStat<u32>,
+        PeakTrackedStat<u16>,
+        PeakTrackedStat<u8>,
+        PeakTrackedStat<f64>,
+        PeakTrackedStat<f32>,
+        PeakTrackedStat<i128>,
+        PeakTrackedStat<i64>,
+        PeakTrackedStat<i32>,
+        PeakTrackedStat<i16>,
+        PeakTrackedStat<i8>
+    );
+    register!(
+        HistoryStat<Duration>,
+        HistoryStat<u128>,
+        HistoryStat<u64>,
+        HistoryStat<u32>,
+        HistoryStat<u16>,
+        HistoryStat<u8>,
+        HistoryStat<f64>,
+        HistoryStat<f32>,
+        HistoryStat<i128>,
+        HistoryStat<i64>,
+        HistoryStat<i32>,
+        HistoryStat<i16>,
+        HistoryStat<i8>
+    );
+    register!(
+        ArithStat<u128>,
+        ArithStat<u64>,
+        ArithStat<u32>,
+        ArithStat<u16>,
+        ArithStat<u8>,
+        ArithStat<i128>,
+        ArithStat<i64>,
+        ArithStat<i32>,
+        ArithStat<i16>,
+        ArithStat<i8>
+    );
+    register!(
+        ListStat<String>,
+        ListStat<bool>,
+        ListStat<u64>,
+        ListStat<u32>,
+        ListStat<u16>,
+        ListStat<u8>,
+        ListStat<i64>,
+        ListStat<i32>,
+        ListStat<i16>,
+        ListStat<i8>,
+        ListStat<f64>,
+        ListStat<f32>
+    );
+    register!(
+        ModifierStack<Duration>,
+        ModifierStack<u128>,
+        ModifierStack<u64>,
+        ModifierStack<u32>,
+        ModifierStack<u16>,
+        ModifierStack<u8>,
+        ModifierStack<f64>,
+        ModifierStack<f32>,
+        ModifierStack<i128>,
+        ModifierStack<i64>,
+        ModifierStack<i32>,
+        ModifierStack<i16>,
+        ModifierStack<i8>
+    );
+    register!(ApproxFloat<f64>, ApproxFloat<f32>);
+    register!(MovingAverageStat, DiscriminantStat, Numeric);
+}
+
+/// Implements [`StatData::compare`] for a type by downcasting the other stat to the same type
+macro_rules! compare_same_type {
+    ($ty:ty) => {
+        fn compare(&self, other: &dyn StatData) -> Option<Ordering> {
+            self.partial_cmp(other.downcast_ref::<$ty>()?)
+        }
+    };
+}
+
+/// Implements [`StatData::eq_dyn`] for a type by downcasting the other stat to the same type
+macro_rules! eq_same_type {
+    ($ty:ty) => {
+        fn eq_dyn(&self, other: &dyn StatData) -> bool {
+            other.downcast_ref::<$ty>() == Some(self)
+        }
+    };
+}
+
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for Duration {
+    compare_same_type!(Duration);
+    eq_same_type!(Duration);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<Duration>() {
-            *self += *other;
+            *self = self.saturating_add(*other);
         }
     }
 
+    fn apply_percent(&mut self, percent: f64) {
+        let factor = (1.0 + percent / 100.0).max(0.0);
+        *self = Duration::try_from_secs_f64(self.as_secs_f64() * factor).unwrap_or(Duration::MAX);
+    }
+
     fn default(&self) -> Box<dyn StatData> {
         Box::new(Duration::ZERO)
     }
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<Duration>() {
-            *self -= *other;
+            *self = self.saturating_sub(*other);
+        }
+    }
+}
+
+// Timer ---------------------------------------------------
+
+/// Timer stats track cooldowns and recurring counters.
+///
+/// - `add` ticks the timer forward by the other timer's elapsed time or by a [`Duration`]. A paused timer doesn't advance
+/// - `sub` rewinds the elapsed time by the other timer's elapsed time or by a [`Duration`], saturating at zero and
+///   clearing the finished state
+/// - `default` is a zero duration timer
+///
+/// The [`TimerMode`](bevy::time::TimerMode) of the stored timer is kept by every operation except `set`, which
+/// replaces the timer entirely
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for Timer {
+    eq_same_type!(Timer);
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<Timer>() {
+            self.tick(other.elapsed());
+        } else if let Some(other) = other.downcast_ref::<Duration>() {
+            self.tick(*other);
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(Timer::new(Duration::ZERO, self.mode()))
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        let amount = if let Some(other) = other.downcast_ref::<Timer>() {
+            other.elapsed()
+        } else if let Some(other) = other.downcast_ref::<Duration>() {
+            *other
+        } else {
+            return;
+        };
+        let elapsed = self.elapsed().saturating_sub(amount);
+        self.reset();
+        self.set_elapsed(elapsed);
+    }
+}
+
+/// Implements [`StatData::apply_percent`] for an integer type, rounding the change to the nearest whole number and
+/// saturating at the bounds of the type
+macro_rules! apply_percent_int {
+    ($ty:ty) => {
+        fn apply_percent(&mut self, percent: f64) {
+            let delta = (*self as f64 * percent / 100.0).round();
+            if delta >= 0.0 {
+                *self = self.saturating_add(delta as $ty);
+            } else {
+                *self = self.saturating_sub((-delta) as $ty);
+            }
+        }
+    };
+}
+
+/// Implements [`StatData::apply_percent`] for a float type, leaving the stat unchanged if it or the percentage isn't
+/// finite and clamping an overflowing result to the finite range of the type
+macro_rules! apply_percent_float {
+    ($ty:ty) => {
+        fn apply_percent(&mut self, percent: f64) {
+            if !self.is_finite() || !percent.is_finite() {
+                return;
+            }
+            let value = *self as f64 + *self as f64 * percent / 100.0;
+            *self = value.clamp(<$ty>::MIN as f64, <$ty>::MAX as f64) as $ty;
+        }
+    };
+}
+
+// Char ---------------------------------------------------
+
+/// Char stats hold categorical state such as a grade or a key binding and are only changed by setting them.
+///
+/// - `add` and `sub` are no-ops
+/// - `default` is `'\0'`
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for char {
+    compare_same_type!(char);
+    eq_same_type!(char);
+
+    fn add(&mut self, _other: Box<dyn StatData>) {}
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new('\0')
+    }
+
+    fn sub(&mut self, _other: Box<dyn StatData>) {}
+}
+
+// Entity ---------------------------------------------------
+
+/// Entity stats hold references to other entities such as the last entity that damaged this one and are only
+/// changed by setting them.
+///
+/// - `add` and `sub` are no-ops
+/// - `default` is [`Entity::PLACEHOLDER`]
+///
+/// **Entities are only meaningful inside the [`World`](bevy::ecs::world::World) they came from.** They are
+/// serialized as their raw bits so saved entity stats will point at unrelated or missing entities once loaded in
+/// another session. Remove or reset them before saving if the collection is persisted.
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for Entity {
+    compare_same_type!(Entity);
+    eq_same_type!(Entity);
+
+    fn add(&mut self, _other: Box<dyn StatData>) {}
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(Entity::PLACEHOLDER)
+    }
+
+    fn sub(&mut self, _other: Box<dyn StatData>) {}
+}
+
+// Bool ---------------------------------------------------
+
+/// Boolean stats act as flags.
+///
+/// - `add` is a logical OR, adding `true` sets the flag and adding `false` leaves it unchanged
+/// - `sub` is a logical AND NOT, subtracting `true` clears the flag and subtracting `false` leaves it unchanged
+/// - `toggle` flips the flag
+/// - `default` is `false`
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for bool {
+    eq_same_type!(bool);
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<bool>() {
+            *self = *self || *other;
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(false)
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<bool>() {
+            *self = *self && !*other;
+        }
+    }
+
+    fn toggle(&mut self) {
+        *self = !*self;
+    }
+}
+
+// String ---------------------------------------------------
+
+/// String stats append on `add` and remove the first occurrence of the other string on `sub`
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for String {
+    eq_same_type!(String);
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<String>() {
+            self.push_str(other);
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(String::new())
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<String>() {
+            if let Some(index) = self.find(other.as_str()) {
+                self.replace_range(index..index + other.len(), "");
+            }
         }
     }
 }
@@ -25,6 +402,10 @@ impl StatData for Duration {
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for u128 {
+    apply_percent_int!(u128);
+    compare_same_type!(u128);
+    eq_same_type!(u128);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<u128>() {
             *self = self.saturating_add(*other);
@@ -44,6 +425,10 @@ impl StatData for u128 {
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for u64 {
+    apply_percent_int!(u64);
+    compare_same_type!(u64);
+    eq_same_type!(u64);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<u64>() {
             *self = self.saturating_add(*other);
@@ -63,6 +448,10 @@ impl StatData for u64 {
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for u32 {
+    apply_percent_int!(u32);
+    compare_same_type!(u32);
+    eq_same_type!(u32);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<u32>() {
             *self = self.saturating_add(*other);
@@ -82,6 +471,10 @@ impl StatData for u32 {
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for u16 {
+    apply_percent_int!(u16);
+    compare_same_type!(u16);
+    eq_same_type!(u16);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<u16>() {
             *self = self.saturating_add(*other);
@@ -101,6 +494,10 @@ impl StatData for u16 {
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for u8 {
+    apply_percent_int!(u8);
+    compare_same_type!(u8);
+    eq_same_type!(u8);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<u8>() {
             *self = self.saturating_add(*other);
@@ -120,11 +517,63 @@ impl StatData for u8 {
 
 // FLOATS ---------------------------------------------------
 
+/// Evaluates a float operation, returning the current value unchanged if either operand isn't finite and clamping
+/// an overflowing result to the finite range of the type
+macro_rules! finite_float {
+    ($ty:ty, $current:expr, $op:tt, $other:expr) => {{
+        let (current, other): ($ty, $ty) = ($current, $other);
+        if !current.is_finite() || !other.is_finite() {
+            current
+        } else {
+            (current $op other).clamp(<$ty>::MIN, <$ty>::MAX)
+        }
+    }};
+}
+
+/// Implements [`StatData::compare`] and [`StatData::eq_dyn`] for a float, treating values within the type's epsilon
+/// scaled by their magnitude as equal so rounding drift isn't reported as a change.
+///
+/// Use [`ApproxFloat`](crate::approx::ApproxFloat) for a custom tolerance
+macro_rules! approx_float {
+    ($ty:ty) => {
+        fn compare(&self, other: &dyn StatData) -> Option<Ordering> {
+            let other = other.downcast_ref::<$ty>()?;
+            if crate::approx::approx_eq(
+                *self,
+                *other,
+                crate::approx::default_tolerance(*self, *other),
+            ) {
+                return Some(Ordering::Equal);
+            }
+            self.partial_cmp(other)
+        }
+
+        fn eq_dyn(&self, other: &dyn StatData) -> bool {
+            other.downcast_ref::<$ty>().is_some_and(|other| {
+                crate::approx::approx_eq(
+                    *self,
+                    *other,
+                    crate::approx::default_tolerance(*self, *other),
+                )
+            })
+        }
+    };
+}
+
+/// Float stats track fractional values such as distance traveled.
+///
+/// - `add`, `sub`, and `apply_percent` ignore `NaN` and infinite values, leaving the stat unchanged, and clamp the
+///   result to the finite range of the type so a single bad value can't poison the stat
+/// - `compare` and `eq_dyn` treat values within a scaled epsilon as equal
+/// - `default` is `0.0`
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for f64 {
+    apply_percent_float!(f64);
+    approx_float!(f64);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<f64>() {
-            *self += other;
+            *self = finite_float!(f64, *self, +, *other);
         }
     }
 
@@ -134,16 +583,19 @@ impl StatData for f64 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<f64>() {
-            *self -= other;
+            *self = finite_float!(f64, *self, -, *other);
         }
     }
 }
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for f32 {
+    apply_percent_float!(f32);
+    approx_float!(f32);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<f32>() {
-            *self += other;
+            *self = finite_float!(f32, *self, +, *other);
         }
     }
 
@@ -153,18 +605,77 @@ impl StatData for f32 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<f32>() {
-            *self -= other;
+            *self = finite_float!(f32, *self, -, *other);
         }
     }
 }
 
+// Non zero ints ---------------------------------------------------
+
+/// Non zero stats operate on their inner value and clamp the result to a minimum of 1.
+///
+/// - `add` saturates at the maximum of the type
+/// - `sub` clamps to 1, subtracting down to or below zero results in 1 rather than zero
+/// - `default` is 1
+///
+/// Both the non zero type and its plain integer type are accepted by `add` and `sub`
+macro_rules! impl_non_zero_stat {
+    ($($ty:ty => $inner:ty),*) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde)]
+            impl StatData for $ty {
+                compare_same_type!($ty);
+                eq_same_type!($ty);
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<$ty>() {
+                        other.get()
+                    } else if let Some(other) = other.downcast_ref::<$inner>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    *self = self.saturating_add(other);
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    let mut value = self.get();
+                    StatData::apply_percent(&mut value, percent);
+                    *self = <$ty>::new(value).unwrap_or(<$ty>::MIN);
+                }
+
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(<$ty>::MIN)
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<$ty>() {
+                        other.get()
+                    } else if let Some(other) = other.downcast_ref::<$inner>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    *self = <$ty>::new(self.get().saturating_sub(other)).unwrap_or(<$ty>::MIN);
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_stat!(NonZeroU32 => u32, NonZeroU64 => u64);
+
 // Signed Ints ---------------------------------------------------
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for i128 {
+    apply_percent_int!(i128);
+    compare_same_type!(i128);
+    eq_same_type!(i128);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i128>() {
-            *self += other;
+            *self = self.saturating_add(*other);
         }
     }
 
@@ -174,16 +685,20 @@ impl StatData for i128 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i128>() {
-            *self -= other;
+            *self = self.saturating_sub(*other);
         }
     }
 }
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for i64 {
+    apply_percent_int!(i64);
+    compare_same_type!(i64);
+    eq_same_type!(i64);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i64>() {
-            *self += other;
+            *self = self.saturating_add(*other);
         }
     }
 
@@ -193,16 +708,20 @@ impl StatData for i64 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i64>() {
-            *self -= other;
+            *self = self.saturating_sub(*other);
         }
     }
 }
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for i32 {
+    apply_percent_int!(i32);
+    compare_same_type!(i32);
+    eq_same_type!(i32);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i32>() {
-            *self += other;
+            *self = self.saturating_add(*other);
         }
     }
 
@@ -212,16 +731,20 @@ impl StatData for i32 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i32>() {
-            *self -= other;
+            *self = self.saturating_sub(*other);
         }
     }
 }
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for i16 {
+    apply_percent_int!(i16);
+    compare_same_type!(i16);
+    eq_same_type!(i16);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i16>() {
-            *self += other;
+            *self = self.saturating_add(*other);
         }
     }
 
@@ -231,16 +754,20 @@ impl StatData for i16 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i16>() {
-            *self -= other;
+            *self = self.saturating_sub(*other);
         }
     }
 }
 
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for i8 {
+    apply_percent_int!(i8);
+    compare_same_type!(i8);
+    eq_same_type!(i8);
+
     fn add(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i8>() {
-            *self += other;
+            *self = self.saturating_add(*other);
         }
     }
 
@@ -250,7 +777,270 @@ impl StatData for i8 {
 
     fn sub(&mut self, other: Box<dyn StatData>) {
         if let Some(other) = other.downcast_ref::<i8>() {
-            *self -= other;
+            *self = self.saturating_sub(*other);
         }
     }
 }
+
+// Vectors ---------------------------------------------------
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for Vec2 {
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<Vec2>() {
+            *self += *other;
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(Vec2::ZERO)
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<Vec2>() {
+            *self -= *other;
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for Vec3 {
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<Vec3>() {
+            *self += *other;
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(Vec3::ZERO)
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<Vec3>() {
+            *self -= *other;
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for IVec2 {
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<IVec2>() {
+            *self += *other;
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(IVec2::ZERO)
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<IVec2>() {
+            *self -= *other;
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for IVec3 {
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<IVec3>() {
+            *self += *other;
+        }
+    }
+
+    fn default(&self) -> Box<dyn StatData> {
+        Box::new(IVec3::ZERO)
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = other.downcast_ref::<IVec3>() {
+            *self -= *other;
+        }
+    }
+}
+
+// Options ---------------------------------------------------
+
+/// Optional stats represent values that are unset until first recorded.
+///
+/// - `add` adopts the incoming value if this is `None`, otherwise it delegates to the inner `add`. Both the plain
+///   type and another option are accepted, adding `None` does nothing
+/// - `sub` clears the value to `None`
+/// - `default` is `None`
+macro_rules! impl_option_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for Option<$ty> {
+                eq_same_type!(Option<$ty>);
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<Option<$ty>>() {
+                        let Some(other) = other else {
+                            return;
+                        };
+                        other.clone()
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        other.clone()
+                    } else {
+                        return;
+                    };
+                    match self {
+                        Some(value) => StatData::add(value, Box::new(other)),
+                        None => *self = Some(other),
+                    }
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    if let Some(value) = self {
+                        StatData::apply_percent(value, percent);
+                    }
+                }
+
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(None::<$ty>)
+                }
+
+                fn sub(&mut self, _other: Box<dyn StatData>) {
+                    *self = None;
+                }
+            }
+        )*
+    };
+}
+
+impl_option_stat!(
+    Duration => "Option<Duration>",
+    bool => "Option<bool>",
+    String => "Option<String>",
+    u128 => "Option<u128>",
+    u64 => "Option<u64>",
+    u32 => "Option<u32>",
+    u16 => "Option<u16>",
+    u8 => "Option<u8>",
+    f64 => "Option<f64>",
+    f32 => "Option<f32>",
+    i128 => "Option<i128>",
+    i64 => "Option<i64>",
+    i32 => "Option<i32>",
+    i16 => "Option<i16>",
+    i8 => "Option<i8>",
+);
+
+// Maps ---------------------------------------------------
+
+/// Map stats track a count per string key, such as how many of each crop has been grown.
+///
+/// - `add` merges the other map in, summing the values of keys present in both and inserting missing keys
+/// - `sub` subtracts the values of keys present in both maps, saturating at zero rather than removing the key or
+///   going negative. Keys only present in the other map are ignored
+/// - `apply_percent` is applied to every value in the map
+/// - `default` is an empty map
+///
+/// These are implemented for the std [`HashMap`]. With [`StatData`] in scope `HashMap::default()` is ambiguous
+/// with [`StatData::default`], use `HashMap::new()` instead
+macro_rules! impl_map_stat {
+    ($($value:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for HashMap<String, $value> {
+                eq_same_type!(HashMap<String, $value>);
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    let Some(other) = other.downcast_ref::<HashMap<String, $value>>() else {
+                        return;
+                    };
+                    for (key, value) in other.iter() {
+                        StatData::add(self.entry(key.clone()).or_default(), Box::new(*value));
+                    }
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    let Some(other) = other.downcast_ref::<HashMap<String, $value>>() else {
+                        return;
+                    };
+                    for (key, value) in other.iter() {
+                        if let Some(current) = self.get_mut(key) {
+                            StatData::sub(current, Box::new(*value));
+                            *current = (*current).max(0 as $value);
+                        }
+                    }
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    for value in self.values_mut() {
+                        StatData::apply_percent(value, percent);
+                    }
+                }
+
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(<HashMap<String, $value> as Default>::default())
+                }
+            }
+        )*
+    };
+}
+
+impl_map_stat!(
+    u64 => "HashMap<String, u64>",
+    f64 => "HashMap<String, f64>",
+);
+
+// Tuples ---------------------------------------------------
+
+/// Pair stats keep two related values together, such as wins and losses, so they are reset and saved as one.
+///
+/// - `add` and `sub` are applied element-wise using the element type's own `add` and `sub`. Any other type,
+///   including a pair of a different element type, is silently skipped the same as every other built in stat
+/// - `apply_percent` is applied to both elements
+/// - `default` is a pair of zeros
+macro_rules! impl_pair_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for ($ty, $ty) {
+                eq_same_type!(($ty, $ty));
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    if let Some(other) = other.downcast_ref::<($ty, $ty)>() {
+                        StatData::add(&mut self.0, Box::new(other.0));
+                        StatData::add(&mut self.1, Box::new(other.1));
+                    }
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    if let Some(other) = other.downcast_ref::<($ty, $ty)>() {
+                        StatData::sub(&mut self.0, Box::new(other.0));
+                        StatData::sub(&mut self.1, Box::new(other.1));
+                    }
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    StatData::apply_percent(&mut self.0, percent);
+                    StatData::apply_percent(&mut self.1, percent);
+                }
+
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new((<$ty as Default>::default(), <$ty as Default>::default()))
+                }
+            }
+        )*
+    };
+}
+
+impl_pair_stat!(
+    Duration => "(Duration, Duration)",
+    u128 => "(u128, u128)",
+    u64 => "(u64, u64)",
+    u32 => "(u32, u32)",
+    u16 => "(u16, u16)",
+    u8 => "(u8, u8)",
+    f64 => "(f64, f64)",
+    f32 => "(f32, f32)",
+    i128 => "(i128, i128)",
+    i64 => "(i64, i64)",
+    i32 => "(i32, i32)",
+    i16 => "(i16, i16)",
+    i8 => "(i8, i8)",
+);
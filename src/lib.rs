@@ -1,32 +1,290 @@
 //! A simple system to track stats in one place using a single system.
 
-use std::fmt::Debug;
+// Allows the derive macros to refer to `::bevy_easy_stats` from inside this crate
+extern crate self as bevy_easy_stats;
 
-use bevy::{prelude::SystemSet, utils::hashbrown::HashMap};
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    sync::Arc,
+};
+
+use bevy::{
+    app::{App, Plugin, PostUpdate},
+    prelude::{IntoSystemSetConfigs, Resource, SystemSet},
+    utils::{hashbrown::HashMap, Instant},
+};
 use downcast_rs::{impl_downcast, Downcast};
 use dyn_clone::{clone_trait_object, DynClone};
 
+#[cfg(feature = "reflect")]
+use bevy::reflect::{reflect_trait, std_traits::ReflectDefault, PartialReflect, Reflect};
 #[cfg(feature = "serde")]
 use serde::Deserialize;
 
-pub use commands::{ModifyStatEntityCommands, StatCommandsExt, StatEntityCommandsExt};
-pub use events::{ModifyStat, StatAppExt};
+#[cfg(feature = "derive")]
+pub use bevy_easy_stats_derive::StatIdentifier;
+#[cfg(feature = "serde")]
+pub use bundle::SaveBundle;
+pub use collection::StatCollection;
+pub use commands::{
+    ModifyStatEntityCommands, StatCommandsExt, StatEntityCommandsExt, StatModified, StatQueryExt,
+};
+#[cfg(feature = "egui")]
+pub use debug_ui::stats_debug_window;
+use derived::{DerivedCycleError, DerivedStat};
+pub use events::{apply_pending_stats, ModifyEntityStat, ModifyStat, StatAppExt, StatChanged};
+pub use global::{GlobalStats, StatReader, StatWriter};
+pub use metadata::StatMetadata;
+pub use modification_log::{LoggedModification, ModificationLog};
+use observer::StatObserver;
+pub use observer::StatObserverFn;
+use rate_limit::RateLimiter;
+#[cfg(feature = "serde")]
+pub use registry::{is_registered_stat_type, registered_stat_types};
+pub use stat_id::{StatId, TypedStatIdentifier};
+use stat_modification::{CustomModification, ModificationKind, ModificationType};
+pub use view::{StatsView, StatsViewMut};
 
+mod alias;
+pub mod approx;
+pub mod arith;
+pub mod average;
+pub mod bounded;
+#[cfg(feature = "serde")]
+mod bundle;
+mod collection;
 mod commands;
+pub mod counter;
+#[cfg(feature = "egui")]
+mod debug_ui;
+pub mod decay;
+pub mod derived;
+pub mod discriminant;
 mod events;
+pub mod floor;
+mod global;
+pub mod history;
 mod implementations;
+pub mod list;
+mod metadata;
+mod modification_log;
+pub mod modifier;
+mod newtype;
+pub mod numeric;
+mod observer;
+pub mod peak;
+#[cfg(feature = "serde")]
+mod persistence;
+pub mod rate_limit;
+#[cfg(feature = "serde")]
+mod registry;
+mod stat_id;
 pub mod stat_modification;
+#[cfg(feature = "serde")]
+mod streaming;
+#[cfg(test)]
+mod test_utils;
+pub mod threshold;
+mod view;
 
+/// The [`PostUpdate`] system sets stat modifications are applied in, ordered `BeforeModifications`,
+/// `ApplyModifications`, then `AfterModifications`
 #[derive(SystemSet, Hash, Debug, Eq, PartialEq, Clone)]
 pub enum StatSystemSets {
+    /// An empty anchor for systems that have to run before any stat modification is applied, such as gathering input
+    BeforeModifications,
+    /// Where every registered stat collection applies its modification events
     ApplyModifications,
+    /// An empty anchor for systems that have to see every stat modification from this frame, such as UI
+    AfterModifications,
+}
+
+/// Marks that the [`StatSystemSets`] were already ordered in this app
+#[derive(Resource)]
+struct StatSystemSetsConfigured;
+
+/// Orders the [`StatSystemSets`] in [`PostUpdate`] the first time it is called for an app
+pub(crate) fn configure_stat_system_sets(app: &mut App) {
+    if app.world().contains_resource::<StatSystemSetsConfigured>() {
+        return;
+    }
+    app.insert_resource(StatSystemSetsConfigured);
+    app.configure_sets(
+        PostUpdate,
+        (
+            StatSystemSets::BeforeModifications,
+            StatSystemSets::ApplyModifications,
+            StatSystemSets::AfterModifications,
+        )
+            .chain(),
+    );
+}
+
+/// Configures the [`StatSystemSets`] in [`PostUpdate`] so that every registered stat collection shares the same ordered sets.
+///
+/// Stat resources can be registered before or after this plugin is added, registering a stat collection orders the
+/// sets as well so the plugin is optional.
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        configure_stat_system_sets(app);
+    }
+}
+
+/// How [`Stats::merge_with`] and [`Stats::rename_stat`] combine a stat with one that already exists
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MergeMode {
+    /// Adds the incoming [`StatData`] to the existing stat
+    Add,
+    /// Replaces the existing stat with the incoming [`StatData`]
+    Overwrite,
+    /// Keeps the existing stat and ignores the incoming [`StatData`]
+    KeepExisting,
+}
+
+/// A stat captured before a modification to tell whether the modification changed it, see [`Stats::changed_from`]
+enum PreviousStat {
+    /// The stat didn't exist
+    Missing,
+    /// The stat can't be compared with [`StatData::eq_dyn`] so it wasn't cloned
+    Incomparable,
+    /// A clone of the stat
+    Comparable(Box<dyn StatData>),
+}
+
+/// How a stat differs between two [`Stats`] collections, see [`Stats::diff`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatDiffKind {
+    /// The stat only exists in the newer collection
+    Added,
+    /// The stat only exists in the previous collection
+    Removed,
+    /// The stat exists in both collections but [`StatData::eq_dyn`] returned false
+    Changed,
+}
+
+/// A single stat that differs between two [`Stats`] collections, see [`Stats::diff`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StatDiff {
+    stat_id: String,
+    kind: StatDiffKind,
+}
+
+impl StatDiff {
+    /// The id of the stat that differs
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// How the stat differs
+    pub fn kind(&self) -> StatDiffKind {
+        self.kind
+    }
+}
+
+/// Why [`Stats::get_stat_typed`] couldn't return a stat or [`Stats::set_same_type`] refused to set one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatAccessError {
+    /// No stat exists under the id
+    NotFound,
+    /// The stat exists but is a different type than requested
+    TypeMismatch {
+        /// The type name of the requested type, or of the stored type for [`Stats::set_same_type`]
+        expected: &'static str,
+        /// The type name of the stored type, or of the rejected data for [`Stats::set_same_type`]. See
+        /// [`StatData::type_name`]
+        found: &'static str,
+    },
+}
+
+impl Display for StatAccessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatAccessError::NotFound => f.write_str("stat not found"),
+            StatAccessError::TypeMismatch { expected, found } => {
+                write!(f, "expected stat of type {expected} but found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StatAccessError {}
+
+/// A deep copy of a [`Stats`] collection created with [`Stats::snapshot`] that can be restored later
+#[derive(Debug, Clone)]
+pub struct StatsSnapshot {
+    stats: HashMap<String, Box<dyn StatData>>,
 }
 
 /// An object containing mappings from a [`StatIdentifier`] to a [`StatData`]
+///
+/// Stats are serialized sorted by id so saved output is stable between runs.
+///
+/// With the `reflect` feature the map itself is not reflected, use [`Stats::get_stat_reflect`] and
+/// [`Stats::iter_reflect`] to read the stored values through reflection.
+///
+/// With the `serde` feature the serialized form carries a `version` field set to [`CURRENT_STATS_VERSION`] so
+/// saves can be migrated, see [`Stats::loaded_version`].
+///
+/// The underlying map is private. Code that used the old `stats` field directly should use [`Stats::iter`],
+/// [`Stats::iter_mut`], [`Stats::len`], [`Stats::contains_stat_manual`], [`Stats::clear`], or consume the
+/// collection with [`IntoIterator`] instead.
+///
+/// Cloning creates a deep copy of every stat. The observer installed with [`Stats::set_observer`] is not cloned, so
+/// modifying a clone never notifies the original's observer
 #[derive(Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default))]
 pub struct Stats {
-    pub stats: HashMap<String, Box<dyn StatData>>,
+    #[cfg(feature = "serde")]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    #[serde(
+        rename = "version",
+        default = "legacy_stats_version",
+        serialize_with = "serialize_stats_version",
+        deserialize_with = "deserialize_stats_version"
+    )]
+    loaded_version: Option<u32>,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_sorted"))]
+    stats: HashMap<String, Box<dyn StatData>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    derived: Vec<DerivedStat>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    last_modified: Option<HashMap<String, Instant>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    rate_limiter: Option<RateLimiter>,
+    #[cfg_attr(feature = "serde", serde(default, serialize_with = "serialize_sorted"))]
+    metadata: HashMap<String, StatMetadata>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    aliases: HashMap<String, String>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    observer: Option<StatObserver>,
+}
+
+impl Clone for Stats {
+    fn clone(&self) -> Self {
+        Stats {
+            #[cfg(feature = "serde")]
+            loaded_version: self.loaded_version,
+            stats: self.stats.clone(),
+            derived: self.derived.clone(),
+            last_modified: self.last_modified.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            metadata: self.metadata.clone(),
+            aliases: self.aliases.clone(),
+            observer: None,
+        }
+    }
 }
 
 impl Stats {
@@ -35,53 +293,480 @@ impl Stats {
         Stats::default()
     }
 
+    /// Creates a new stats object that records when each stat was last modified, see [`Stats::last_modified`]
+    pub fn with_change_tracking() -> Stats {
+        Stats {
+            last_modified: Some(HashMap::default()),
+            ..Stats::default()
+        }
+    }
+
+    /// Creates a new stats object with space for at least `capacity` stats before it has to reallocate.
+    ///
+    /// Useful when spawning many entities that are known to carry a large number of stats
+    pub fn with_capacity(capacity: usize) -> Stats {
+        Stats {
+            stats: HashMap::with_capacity_and_hasher(capacity, Default::default()),
+            ..Stats::default()
+        }
+    }
+
+    /// Reserves space for at least `additional` more stats before the collection has to reallocate
+    pub fn reserve(&mut self, additional: usize) {
+        self.stats.reserve(additional);
+    }
+
+    /// Returns the number of stats the collection can hold without reallocating
+    pub fn capacity(&self) -> usize {
+        self.stats.capacity()
+    }
+
+    /// Returns when the given str id was last modified through this collection.
+    ///
+    /// Always returns `None` unless the collection was created with [`Stats::with_change_tracking`]. Changes
+    /// made through [`Stats::iter_mut`] are not tracked.
+    pub fn last_modified_manual(&self, stat_id: &str) -> Option<Instant> {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        self.last_modified.as_ref()?.get(stat_id).copied()
+    }
+
+    /// Returns when the given [`StatIdentifier`] was last modified through this collection.
+    ///
+    /// See [`Stats::last_modified_manual`]
+    pub fn last_modified(&self, stat_id: &impl StatIdentifier) -> Option<Instant> {
+        self.last_modified_manual(stat_id.identifier())
+    }
+
+    /// Installs a [`RateLimiter`] that guards every modification applied through [`Stats::apply_modification_manual`],
+    /// replacing any existing one.
+    ///
+    /// Commands and events apply modifications through that path so they are guarded too. Calling the direct
+    /// methods such as [`Stats::add_to_stat`] bypasses the limiter
+    pub fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = Some(rate_limiter);
+    }
+
+    /// Returns the installed [`RateLimiter`] if there is one
+    pub fn rate_limiter_mut(&mut self) -> Option<&mut RateLimiter> {
+        self.rate_limiter.as_mut()
+    }
+
+    /// Installs a callback that is called with the stat id and [`ModificationKind`] of every modification made to
+    /// this collection, replacing any existing one.
+    ///
+    /// The observer sees the same changes as [`Stats::last_modified`] plus removals, whether they come from
+    /// commands, events, or direct calls. Bulk operations such as [`Stats::clear`] and [`Stats::restore`] and changes
+    /// made through [`Stats::iter_mut`] are not reported. Mutable access such as [`Stats::get_stat_downcast_mut`] is
+    /// reported as [`ModificationKind::Custom`] when the access is handed out, before any change is made, except for
+    /// [`Stats::get_or_insert_with`] which is only reported once it inserts the stat.
+    /// [`ModificationKind::SetMax`], [`ModificationKind::SetMin`], and [`ModificationKind::Clamp`] modifications that
+    /// replace the stat are reported as [`ModificationKind::Set`].
+    ///
+    /// The observer is called while this collection is mutably borrowed, so it must not try to modify it. Clones of
+    /// the collection start without an observer
+    pub fn set_observer(&mut self, observer: StatObserverFn) {
+        self.observer = Some(StatObserver::new(observer));
+    }
+
+    /// Removes the observer installed with [`Stats::set_observer`], returning true if there was one
+    pub fn clear_observer(&mut self) -> bool {
+        self.observer.take().is_some()
+    }
+
+    /// Sets the stat if it doesn't exist or the given data compares to it with the given ordering
+    fn set_if_ordered_manual(
+        &mut self,
+        stat_id: &str,
+        stat_data: Box<dyn StatData>,
+        ordering: Ordering,
+    ) {
+        let replace = match self.stats.get(stat_id) {
+            Some(stat) => stat_data.as_ref().compare(stat.as_ref()) == Some(ordering),
+            None => true,
+        };
+        if replace {
+            self.set_stat_manual(stat_id, stat_data);
+        }
+    }
+
+    /// Sets the stat to the nearest bound if it exists and is outside of the range
+    fn clamp_stat_manual(&mut self, stat_id: &str, min: Box<dyn StatData>, max: Box<dyn StatData>) {
+        if min.as_ref().compare(max.as_ref()) == Some(Ordering::Greater) {
+            return;
+        }
+        let Some(stat) = self.stats.get(stat_id) else {
+            return;
+        };
+        if stat.as_ref().compare(min.as_ref()) == Some(Ordering::Less) {
+            self.set_stat_manual(stat_id, min);
+        } else if stat.as_ref().compare(max.as_ref()) == Some(Ordering::Greater) {
+            self.set_stat_manual(stat_id, max);
+        }
+    }
+
+    fn mark_modified(&mut self, stat_id: &str, kind: ModificationKind) {
+        if let Some(last_modified) = self.last_modified.as_mut() {
+            last_modified.insert(stat_id.to_string(), Instant::now());
+        }
+        if let Some(observer) = self.observer.as_ref() {
+            observer.notify(stat_id, kind);
+        }
+    }
+
+    /// Creates a new stats object starting with the given stats.
+    ///
+    /// Each [`StatData`] is stored as given like [`Stats::set_stat_manual`], if an id appears more than once the last
+    /// value is kept. Use [`StatCommandsExt::spawn_with_stats`] to spawn an entity with seeded stats
+    pub fn seed(stats: Vec<(String, Box<dyn StatData>)>) -> Stats {
+        let mut seeded = Stats::new();
+        seeded.extend(stats);
+        seeded
+    }
+
     /// Adds the given [`StatData`] to the given str id.
     ///
     /// Creates the entry if it doesnt exist
     pub fn add_to_stat_manual(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
+        self.combine_stat_manual(stat_id, stat_data, ModificationKind::Add, |stat, other| {
+            stat.add(other)
+        });
+    }
+
+    /// Adds or subs the given [`StatData`] with the given combine function, creating the entry if it doesnt exist.
+    ///
+    /// Stats ignore data of a type they don't support, so when the types differ the stat is only marked as modified
+    /// if it no longer compares equal to its previous value with [`StatData::eq_dyn`]
+    fn combine_stat_manual(
+        &mut self,
+        stat_id: &str,
+        stat_data: Box<dyn StatData>,
+        kind: ModificationKind,
+        combine: impl FnOnce(&mut Box<dyn StatData>, Box<dyn StatData>),
+    ) {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
         let stat = self
             .stats
             .entry(stat_id.to_string())
             .or_insert(stat_data.default());
-        stat.add(stat_data);
+        let previous = (Downcast::as_any(stat.as_ref()).type_id()
+            != Downcast::as_any(stat_data.as_ref()).type_id())
+        .then(|| stat.clone());
+        combine(stat, stat_data);
+        if previous.is_some_and(|previous| previous.as_ref().eq_dyn(stat.as_ref())) {
+            return;
+        }
+        self.mark_modified(stat_id, kind);
     }
 
     /// Sets the given [`StatData`] under the given str id.
     ///
-    /// Creates the entry if it doesnt exist
+    /// Creates the entry if it doesnt exist. The existing stat is first given the chance to take the value in place
+    /// with [`StatData::set_from`], which lets wrappers such as [`BoundedStat`](bounded::BoundedStat) keep their
+    /// bounds when set to a plain number. Otherwise it is replaced entirely so its type can change, unlike
+    /// [`Stats::add_to_stat_manual`] and [`Stats::sub_from_stat_manual`] which ignore data of a different type. Use
+    /// [`Stats::force_set_manual`] to always replace the stat or [`Stats::set_same_type_manual`] to reject a type
+    /// change
     pub fn set_stat_manual(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
-        self.stats.insert(stat_id.to_string(), stat_data);
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let set_in_place = self
+            .stats
+            .get_mut(stat_id)
+            .is_some_and(|stat| stat.as_mut().set_from(stat_data.as_ref()));
+        if !set_in_place {
+            self.stats.insert(stat_id.to_string(), stat_data);
+        }
+        self.mark_modified(stat_id, ModificationKind::Set);
+    }
+
+    /// Removes the given stat and its corrosponding [`StatData`] and [`StatMetadata`]
+    ///
+    /// Returns true if the stat existed, use [`Stats::take_stat_manual`] to inspect the removed [`StatData`]
+    pub fn remove_stat_manual(&mut self, stat_id: &str) -> bool {
+        self.take_stat_manual(stat_id).is_some()
+    }
+
+    /// Removes the given stat like [`Stats::remove_stat_manual`] and returns its [`StatData`] instead of dropping it.
+    ///
+    /// Useful for moving a stat into another collection without cloning it
+    pub fn take_stat_manual(&mut self, stat_id: &str) -> Option<Box<dyn StatData>> {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        self.metadata.remove(stat_id);
+        if let Some(last_modified) = self.last_modified.as_mut() {
+            last_modified.remove(stat_id);
+        }
+        let stat_data = self.stats.remove(stat_id)?;
+        if let Some(observer) = self.observer.as_ref() {
+            observer.notify(stat_id, ModificationKind::Remove);
+        }
+        Some(stat_data)
     }
 
-    /// Removes the given stat and its corrosponding [`StatData`]
-    pub fn remove_stat_manual(&mut self, stat_id: &str) {
-        self.stats.remove(stat_id);
+    /// Removes the requested [`StatIdentifier`] and returns its [`StatData`], see [`Stats::take_stat_manual`]
+    pub fn take_stat(&mut self, stat_id: &impl StatIdentifier) -> Option<Box<dyn StatData>> {
+        self.take_stat_manual(stat_id.identifier())
     }
 
     /// Sets the given stat to default if it exists. Otherwise does nothing
     pub fn reset_stat_manual(&mut self, stat_id: &str) {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
         let Some(stat) = self.stats.get_mut(stat_id) else {
             return;
         };
 
         *stat = stat.default();
+        self.mark_modified(stat_id, ModificationKind::Reset);
+    }
+
+    /// Flips the given stat, creating it as `true` if it doesn't exist.
+    ///
+    /// Does nothing for stats whose type doesn't support toggling, see [`StatData::toggle`]. The stat is only marked
+    /// as modified if it was created or no longer compares equal to its previous value with [`StatData::eq_dyn`]
+    pub fn toggle_stat_manual(&mut self, stat_id: &str) {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let previous = self.capture_previous(stat_id);
+        match self.stats.get_mut(stat_id) {
+            Some(stat) => stat.toggle(),
+            None => {
+                self.stats.insert(stat_id.to_string(), Box::new(true));
+            }
+        }
+        if self.changed_from(stat_id, &previous) {
+            self.mark_modified(stat_id, ModificationKind::Toggle);
+        }
+    }
+
+    /// Flips the requested [`StatIdentifier`], creating it as `true` if it doesn't exist, see
+    /// [`Stats::toggle_stat_manual`]
+    pub fn toggle_stat(&mut self, stat_id: &impl StatIdentifier) {
+        self.toggle_stat_manual(stat_id.identifier())
+    }
+
+    /// Adds the given percentage of the stats current value to it if it exists. Negative percentages subtract.
+    ///
+    /// Does nothing if the stat doesn't exist, see [`StatData::apply_percent`]. The stat is only marked as modified if
+    /// it no longer compares equal to its previous value with [`StatData::eq_dyn`]
+    pub fn apply_percent_manual(&mut self, stat_id: &str, percent: f64) {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let previous = self.capture_previous(stat_id);
+        let Some(stat) = self.stats.get_mut(stat_id) else {
+            return;
+        };
+        stat.apply_percent(percent);
+        if !self.changed_from(stat_id, &previous) {
+            return;
+        }
+        let kind = if percent < 0.0 {
+            ModificationKind::SubPercent
+        } else {
+            ModificationKind::AddPercent
+        };
+        self.mark_modified(stat_id, kind);
+    }
+
+    /// Adds the given percentage of the stats current value to it if it exists. Negative percentages subtract.
+    ///
+    /// Does nothing if the stat doesn't exist, see [`StatData::apply_percent`]
+    pub fn apply_percent(&mut self, stat_id: &impl StatIdentifier, percent: f64) {
+        self.apply_percent_manual(stat_id.identifier(), percent)
+    }
+
+    /// Serializes the [`StatData`] for the requested [`StatIdentifier`] into a json string.
+    ///
+    /// The typetag tag is included so [`Stats::import_stat`] restores the same concrete type
+    #[cfg(feature = "serde")]
+    pub fn export_stat(&self, stat_id: &impl StatIdentifier) -> Option<String> {
+        let stat = self.get_stat(stat_id)?;
+        serde_json::to_string(stat.as_ref()).ok()
+    }
+
+    /// Deserializes a json string created by [`Stats::export_stat`] and sets it as the [`StatData`] for the requested [`StatIdentifier`]
+    #[cfg(feature = "serde")]
+    pub fn import_stat(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        json: &str,
+    ) -> Result<(), serde_json::Error> {
+        let stat = serde_json::from_str::<Box<dyn StatData>>(json)?;
+        self.set_stat(stat_id, stat);
+        Ok(())
+    }
+
+    /// Returns the format version these stats were deserialized from, or `None` if they weren't deserialized.
+    ///
+    /// Saves written before versioning load as `0`. Stats are always serialized with [`CURRENT_STATS_VERSION`]
+    #[cfg(feature = "serde")]
+    pub fn loaded_version(&self) -> Option<u32> {
+        self.loaded_version
+    }
+
+    /// Serializes every stat and its metadata into a pretty printed RON string, see [`Stats::from_ron`]
+    #[cfg(feature = "ron")]
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Deserializes a RON string created by [`Stats::to_ron`] or written by hand.
+    ///
+    /// Each stat is written as a map holding its typetag tag under `"type"`. Stats serialized as a struct keep their
+    /// fields next to the tag, such as `"Hits": {"type": "CounterStat", "counts": {}}`, while any other value is
+    /// stored under `"value"`, such as `"Gold": {"type": "u64", "value": 5}`
+    #[cfg(feature = "ron")]
+    pub fn from_ron(ron: &str) -> Result<Stats, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Sets every stat to default, keeping their ids and types
+    pub fn reset_all(&mut self) {
+        self.reset_matching(|_| true);
+    }
+
+    /// Sets every stat whose id matches the given predicate to default, keeping their ids and types
+    pub fn reset_matching(&mut self, pred: impl Fn(&str) -> bool) {
+        let now = Instant::now();
+        for (stat_id, stat) in self.stats.iter_mut() {
+            if pred(stat_id) {
+                *stat = stat.default();
+                if let Some(last_modified) = self.last_modified.as_mut() {
+                    last_modified.insert(stat_id.clone(), now);
+                }
+                if let Some(observer) = self.observer.as_ref() {
+                    observer.notify(stat_id, ModificationKind::Reset);
+                }
+            }
+        }
     }
 
     /// Subs the given [`StatData`] from the given str id.
     ///
     /// Creates the entry if it doesnt exist
     pub fn sub_from_stat_manual(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
-        let stat = self
-            .stats
-            .entry(stat_id.to_string())
-            .or_insert(stat_data.default());
-        stat.sub(stat_data);
+        self.combine_stat_manual(stat_id, stat_data, ModificationKind::Sub, |stat, other| {
+            stat.sub(other)
+        });
+    }
+
+    /// Applies the given [`ModificationType`] to the given str id, returning true if the stat changed.
+    ///
+    /// The stat changed if it was created, removed, or no longer compares equal to its previous value with
+    /// [`StatData::eq_dyn`], so modifications of types that don't implement it always count as a change.
+    ///
+    /// If a [`RateLimiter`] is installed and limits the stat, the part of any increase that exceeds the limit is
+    /// undone, whichever kind of modification caused it. See [`RateLimiter`] for which stats can be limited
+    pub fn apply_modification_manual(
+        &mut self,
+        stat_id: &str,
+        modification_type: ModificationType,
+    ) -> bool {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let limited = !matches!(modification_type, ModificationType::Remove)
+            && self
+                .rate_limiter
+                .as_ref()
+                .is_some_and(|rate_limiter| rate_limiter.is_limited(stat_id));
+        if limited {
+            let previous = self.stats.get(stat_id).cloned();
+            self.apply_rate_limited_manual(stat_id, modification_type, previous.as_deref());
+            self.stat_changed(stat_id, previous.as_deref())
+        } else {
+            let previous = self.capture_previous(stat_id);
+            self.apply_unlimited_manual(stat_id, modification_type);
+            self.changed_from(stat_id, &previous)
+        }
+    }
+
+    /// Returns true if the stat under the given str id was created, removed, or no longer compares equal to its
+    /// previous value with [`StatData::eq_dyn`]
+    fn stat_changed(&self, stat_id: &str, previous: Option<&dyn StatData>) -> bool {
+        match (previous, self.stats.get(stat_id)) {
+            (Some(previous), Some(stat)) => !previous.eq_dyn(stat.as_ref()),
+            (None, None) => false,
+            _ => true,
+        }
+    }
+
+    /// Captures the stat under the given str id before a modification, see [`Stats::changed_from`].
+    ///
+    /// A stat that isn't equal to itself doesn't implement [`StatData::eq_dyn`] and counts as changed by every
+    /// modification, so it isn't cloned. This keeps modifying large stats such as a [`ListStat`](list::ListStat) from
+    /// copying them every time
+    fn capture_previous(&self, stat_id: &str) -> PreviousStat {
+        match self.stats.get(stat_id) {
+            None => PreviousStat::Missing,
+            Some(stat) if stat.eq_dyn(stat.as_ref()) => PreviousStat::Comparable(stat.clone()),
+            Some(_) => PreviousStat::Incomparable,
+        }
+    }
+
+    /// Returns true if the stat under the given str id changed from the given [`PreviousStat`]
+    fn changed_from(&self, stat_id: &str, previous: &PreviousStat) -> bool {
+        match previous {
+            PreviousStat::Missing => self.stats.contains_key(stat_id),
+            PreviousStat::Comparable(previous) => {
+                self.stat_changed(stat_id, Some(previous.as_ref()))
+            }
+            PreviousStat::Incomparable => true,
+        }
+    }
+
+    /// Applies the given [`ModificationType`] to the given str id without consulting the [`RateLimiter`]
+    fn apply_unlimited_manual(&mut self, stat_id: &str, modification_type: ModificationType) {
+        match modification_type {
+            ModificationType::Add(data) => self.add_to_stat_manual(stat_id, data),
+            ModificationType::Sub(data) => self.sub_from_stat_manual(stat_id, data),
+            ModificationType::Remove => {
+                self.remove_stat_manual(stat_id);
+            }
+            ModificationType::Set(data) => self.set_stat_manual(stat_id, data),
+            ModificationType::Reset => self.reset_stat_manual(stat_id),
+            ModificationType::AddPercent(percent) => self.apply_percent_manual(stat_id, percent),
+            ModificationType::SubPercent(percent) => self.apply_percent_manual(stat_id, -percent),
+            ModificationType::SetMax(data) => {
+                self.set_if_ordered_manual(stat_id, data, Ordering::Greater)
+            }
+            ModificationType::SetMin(data) => {
+                self.set_if_ordered_manual(stat_id, data, Ordering::Less)
+            }
+            ModificationType::Clamp { min, max } => self.clamp_stat_manual(stat_id, min, max),
+            ModificationType::Toggle => self.toggle_stat_manual(stat_id),
+            ModificationType::Custom(custom) => self.apply_custom_manual(stat_id, custom),
+        }
+    }
+
+    /// Runs the [`CustomModification`] on the given str id, creating the stat from its default first if it has one.
+    ///
+    /// The stat is only marked as modified if it was created or no longer compares equal to its previous value with
+    /// [`StatData::eq_dyn`]
+    fn apply_custom_manual(&mut self, stat_id: &str, custom: CustomModification) {
+        let Some(operation) = custom.take() else {
+            return;
+        };
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let previous = self.capture_previous(stat_id);
+        let stat = match custom.default_stat() {
+            Some(default) => Some(self.stats.entry(stat_id.to_string()).or_insert(default)),
+            None => self.stats.get_mut(stat_id),
+        };
+        if let Some(stat) = stat {
+            operation(stat);
+        }
+        if self.changed_from(stat_id, &previous) {
+            self.mark_modified(stat_id, ModificationKind::Custom);
+        }
     }
 
     /// Gets the [`StatData`] for the requested [`StatIdentifier`].
     #[allow(clippy::borrowed_box)]
     pub fn get_stat_manual(&self, stat_id: &str) -> Option<&Box<dyn StatData>> {
-        self.stats.get(stat_id)
+        self.stats.get(self.resolve_alias(stat_id).as_ref())
     }
 
     /// Adds the given [`StatData`] to the requested [`StatIdentifier`].
@@ -93,13 +778,65 @@ impl Stats {
 
     /// Sets the given [`StatData`] to the requested [`StatIdentifier`].
     ///
-    /// Creates the entry if it doesnt exist
+    /// Creates the entry if it doesnt exist, see [`Stats::set_stat_manual`]
     pub fn set_stat(&mut self, stat_id: &impl StatIdentifier, stat_data: Box<dyn StatData>) {
         self.set_stat_manual(stat_id.identifier(), stat_data)
     }
 
-    /// Removes the given stat and its corrosponding [`StatData`]
-    pub fn remove_stat(&mut self, stat_id: &impl StatIdentifier) {
+    /// Sets the given [`StatData`] under the given str id, replacing the existing stat even if it is a different type.
+    ///
+    /// Unlike [`Stats::set_stat_manual`] the existing stat is never set in place with [`StatData::set_from`], so a
+    /// wrapper such as [`BoundedStat`](bounded::BoundedStat) is replaced along with its bounds
+    pub fn force_set_manual(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        self.stats.insert(stat_id.to_string(), stat_data);
+        self.mark_modified(stat_id, ModificationKind::Set);
+    }
+
+    /// Sets the given [`StatData`] to the requested [`StatIdentifier`] even if it is a different type, see
+    /// [`Stats::force_set_manual`]
+    pub fn force_set(&mut self, stat_id: &impl StatIdentifier, stat_data: Box<dyn StatData>) {
+        self.force_set_manual(stat_id.identifier(), stat_data)
+    }
+
+    /// Sets the given [`StatData`] under the given str id only if it is the same type as the existing stat.
+    ///
+    /// Creates the entry if it doesnt exist. Returns [`StatAccessError::TypeMismatch`] and leaves the stat unchanged
+    /// if the existing stat is a different type
+    pub fn set_same_type_manual(
+        &mut self,
+        stat_id: &str,
+        stat_data: Box<dyn StatData>,
+    ) -> Result<(), StatAccessError> {
+        if let Some(stat) = self.get_stat_manual(stat_id) {
+            if Downcast::as_any(stat.as_ref()).type_id()
+                != Downcast::as_any(stat_data.as_ref()).type_id()
+            {
+                return Err(StatAccessError::TypeMismatch {
+                    expected: stat.as_ref().type_name(),
+                    found: stat_data.as_ref().type_name(),
+                });
+            }
+        }
+        self.set_stat_manual(stat_id, stat_data);
+        Ok(())
+    }
+
+    /// Sets the given [`StatData`] to the requested [`StatIdentifier`] only if it is the same type as the existing
+    /// stat, see [`Stats::set_same_type_manual`]
+    pub fn set_same_type(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        stat_data: Box<dyn StatData>,
+    ) -> Result<(), StatAccessError> {
+        self.set_same_type_manual(stat_id.identifier(), stat_data)
+    }
+
+    /// Removes the given stat and its corrosponding [`StatData`].
+    ///
+    /// Returns true if the stat existed, see [`Stats::remove_stat_manual`]
+    pub fn remove_stat(&mut self, stat_id: &impl StatIdentifier) -> bool {
         self.remove_stat_manual(stat_id.identifier())
     }
 
@@ -115,10 +852,83 @@ impl Stats {
         self.sub_from_stat_manual(stat_id.identifier(), stat_data)
     }
 
+    /// Applies the given [`ModificationType`] to the requested [`StatIdentifier`], returning true if the stat changed.
+    ///
+    /// See [`Stats::apply_modification_manual`]
+    pub fn apply_modification(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        modification_type: ModificationType,
+    ) -> bool {
+        self.apply_modification_manual(stat_id.identifier(), modification_type)
+    }
+
+    /// Applies the given [`ModificationType`] to the given str id only if the condition holds, returning whether it did.
+    ///
+    /// The condition sees the collection before the modification is applied, see [`Stats::apply_modification_manual`]
+    pub fn modify_if_manual(
+        &mut self,
+        stat_id: &str,
+        modification_type: ModificationType,
+        cond: impl Fn(&Stats) -> bool,
+    ) -> bool {
+        if !cond(self) {
+            return false;
+        }
+        self.apply_modification_manual(stat_id, modification_type);
+        true
+    }
+
+    /// Applies the given [`ModificationType`] to the requested [`StatIdentifier`] only if the condition holds, see
+    /// [`Stats::modify_if_manual`]
+    pub fn modify_if(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        modification_type: ModificationType,
+        cond: impl Fn(&Stats) -> bool,
+    ) -> bool {
+        self.modify_if_manual(stat_id.identifier(), modification_type, cond)
+    }
+
+    /// Returns the value the stat under the given str id would have after applying the given [`ModificationType`]
+    /// without changing this collection.
+    ///
+    /// The stat is cloned and the modification applied to the clone, the same as [`Stats::apply_modification_manual`]
+    /// except that the [`RateLimiter`] isn't consulted. Returns `None` if the stat wouldn't exist afterwards, such as
+    /// for [`ModificationType::Remove`] or a reset of a missing stat. Also returns `None` for
+    /// [`ModificationType::Custom`], its operation only runs once so previewing it would spend it
+    pub fn preview_manual(
+        &self,
+        stat_id: &str,
+        modification_type: &ModificationType,
+    ) -> Option<Box<dyn StatData>> {
+        if matches!(modification_type, ModificationType::Custom(_)) {
+            return None;
+        }
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let mut preview = Stats::new();
+        if let Some(stat) = self.stats.get(stat_id) {
+            preview.stats.insert(stat_id.to_string(), stat.clone());
+        }
+        preview.apply_modification_manual(stat_id, modification_type.clone());
+        preview.stats.remove(stat_id)
+    }
+
+    /// Returns the value the requested [`StatIdentifier`] would have after applying the given [`ModificationType`],
+    /// see [`Stats::preview_manual`]
+    pub fn preview(
+        &self,
+        stat_id: &impl StatIdentifier,
+        modification_type: &ModificationType,
+    ) -> Option<Box<dyn StatData>> {
+        self.preview_manual(stat_id.identifier(), modification_type)
+    }
+
     /// Gets the [`StatData`] for the requested [`StatIdentifier`].
     #[allow(clippy::borrowed_box)]
     pub fn get_stat(&self, stat_id: &impl StatIdentifier) -> Option<&Box<dyn StatData>> {
-        self.stats.get(stat_id.identifier())
+        self.get_stat_manual(stat_id.identifier())
     }
 
     /// Gets the [`StatData`] for the requested [`StatIdentifier`] and attempts to downcast it into the given type
@@ -126,46 +936,683 @@ impl Stats {
         &'a self,
         stat_id: &impl StatIdentifier,
     ) -> Option<&'a Stat> {
-        let stat = self.stats.get(stat_id.identifier())?;
+        let stat = self.get_stat_manual(stat_id.identifier())?;
 
         stat.downcast_ref::<Stat>()
     }
-}
-
-/// Represents a unique stat
-pub trait StatIdentifier {
-    /// A unique identifier str for this specific stat identifier
-    fn identifier(&self) -> &'static str;
-}
 
-impl StatIdentifier for Box<dyn StatIdentifier> {
-    fn identifier(&self) -> &'static str {
-        self.as_ref().identifier()
+    /// Gets the [`StatData`] under the given str id and downcasts it into the given type.
+    ///
+    /// Unlike [`Stats::get_stat_downcast`] the error tells a missing stat apart from one of the wrong type
+    pub fn get_stat_typed_manual<Stat: StatData + 'static>(
+        &self,
+        stat_id: &str,
+    ) -> Result<&Stat, StatAccessError> {
+        let stat = self
+            .get_stat_manual(stat_id)
+            .ok_or(StatAccessError::NotFound)?;
+        stat.downcast_ref::<Stat>()
+            .ok_or_else(|| StatAccessError::TypeMismatch {
+                expected: std::any::type_name::<Stat>(),
+                found: stat.as_ref().type_name(),
+            })
     }
-}
 
-/// A type that can be used as a stat
-///
-/// Must include `#[typetag::serde` on any implementations
-#[cfg_attr(feature = "serde", typetag::serde(tag = "type"))]
-pub trait StatData: Downcast + DynClone + Debug + Send + Sync {
-    /// Constructs a new boxed [`StatData`]
-    fn new(stat: Self) -> Box<Self>
-    where
-        Self: Sized,
-    {
-        Box::new(stat)
+    /// Gets the [`StatData`] for the requested [`StatIdentifier`] and downcasts it into the given type, see
+    /// [`Stats::get_stat_typed_manual`]
+    pub fn get_stat_typed<Stat: StatData + 'static>(
+        &self,
+        stat_id: &impl StatIdentifier,
+    ) -> Result<&Stat, StatAccessError> {
+        self.get_stat_typed_manual(stat_id.identifier())
     }
-    /// Creates a new instance of the same kind of stat data
-    fn default(&self) -> Box<dyn StatData>;
-    /// Adds the given other to this stat data
+
+    /// Gets the [`StatData`] for the requested [`StatIdentifier`] mutably and attempts to downcast it into the given type.
+    ///
+    /// With change tracking the stat is marked as modified when this returns `Some`
+    pub fn get_stat_downcast_mut<'a, Stat: StatData + 'static>(
+        &'a mut self,
+        stat_id: &impl StatIdentifier,
+    ) -> Option<&'a mut Stat> {
+        let stat_id = self.resolve_alias(stat_id.identifier());
+        let stat_id = stat_id.as_ref();
+        if !self.stats.get(stat_id)?.is::<Stat>() {
+            return None;
+        }
+        self.mark_modified(stat_id, ModificationKind::Custom);
+
+        self.stats.get_mut(stat_id)?.downcast_mut::<Stat>()
+    }
+
+    /// Downcasts the [`StatData`] under the given str id into the given type and runs the given function on it.
+    ///
+    /// Returns false without running the function if the stat doesn't exist or isn't of the given type, the stat
+    /// is never created
+    pub fn map_stat_downcast_manual<Stat: StatData + 'static>(
+        &mut self,
+        stat_id: &str,
+        f: impl FnOnce(&mut Stat),
+    ) -> bool {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let Some(stat) = self
+            .stats
+            .get_mut(stat_id)
+            .and_then(|stat| stat.downcast_mut::<Stat>())
+        else {
+            return false;
+        };
+        f(stat);
+        self.mark_modified(stat_id, ModificationKind::Custom);
+        true
+    }
+
+    /// Downcasts the [`StatData`] for the requested [`StatIdentifier`] into the given type and runs the given function on it.
+    ///
+    /// See [`Stats::map_stat_downcast_manual`]
+    pub fn map_stat_downcast<Stat: StatData + 'static>(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        f: impl FnOnce(&mut Stat),
+    ) -> bool {
+        self.map_stat_downcast_manual(stat_id.identifier(), f)
+    }
+
+    /// Gets the [`StatData`] under the given str id mutably, inserting the result of the given function if it doesn't exist.
+    ///
+    /// The stat is only marked as modified, and the observer notified, after the function inserts it. Changes made
+    /// through the returned reference are not tracked
+    pub fn get_or_insert_with_manual(
+        &mut self,
+        stat_id: &str,
+        f: impl FnOnce() -> Box<dyn StatData>,
+    ) -> &mut Box<dyn StatData> {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        if !self.stats.contains_key(stat_id) {
+            self.stats.insert(stat_id.to_string(), f());
+            self.mark_modified(stat_id, ModificationKind::Custom);
+        }
+        self.stats
+            .get_mut(stat_id)
+            .expect("the stat exists or was just inserted")
+    }
+
+    /// Gets the [`StatData`] for the requested [`StatIdentifier`] mutably, inserting the result of the given function if it doesn't exist.
+    ///
+    /// See [`Stats::get_or_insert_with_manual`]
+    pub fn get_or_insert_with(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        f: impl FnOnce() -> Box<dyn StatData>,
+    ) -> &mut Box<dyn StatData> {
+        self.get_or_insert_with_manual(stat_id.identifier(), f)
+    }
+
+    /// Returns true if a stat exists under the given str id
+    pub fn contains_stat_manual(&self, stat_id: &str) -> bool {
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        self.stats.contains_key(stat_id)
+    }
+
+    /// Returns true if a stat exists for the requested [`StatIdentifier`]
+    pub fn contains_stat(&self, stat_id: &impl StatIdentifier) -> bool {
+        self.contains_stat_manual(stat_id.identifier())
+    }
+
+    /// Returns true if the stat under the given str id is greater than or equal to the given threshold.
+    ///
+    /// Returns false if the stat doesn't exist or can't be compared with the threshold, see [`StatData::compare`]
+    pub fn stat_at_least_manual(&self, stat_id: &str, threshold: &dyn StatData) -> bool {
+        self.get_stat_manual(stat_id)
+            .and_then(|stat| stat.compare(threshold))
+            .is_some_and(|ordering| ordering.is_ge())
+    }
+
+    /// Returns true if the stat for the requested [`StatIdentifier`] is greater than or equal to the given threshold.
+    ///
+    /// See [`Stats::stat_at_least_manual`]
+    pub fn stat_at_least(&self, stat_id: &impl StatIdentifier, threshold: &dyn StatData) -> bool {
+        self.stat_at_least_manual(stat_id.identifier(), threshold)
+    }
+
+    /// Formats every stat as `id: value` on its own line, sorted by id. See the [`Display`] impl
+    pub fn to_display_string(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the number of stats in this collection
+    pub fn len(&self) -> usize {
+        self.stats.len()
+    }
+
+    /// Returns true if this collection contains no stats
+    pub fn is_empty(&self) -> bool {
+        self.stats.is_empty()
+    }
+
+    /// Removes every stat and its [`StatMetadata`] from this collection
+    pub fn clear(&mut self) {
+        self.stats.clear();
+        self.metadata.clear();
+        if let Some(last_modified) = self.last_modified.as_mut() {
+            last_modified.clear();
+        }
+    }
+
+    /// Removes every given str id and its corrosponding [`StatData`]. Ids that don't exist are ignored
+    pub fn remove_many<'a>(&mut self, stat_ids: impl IntoIterator<Item = &'a str>) {
+        for stat_id in stat_ids {
+            self.remove_stat_manual(stat_id);
+        }
+    }
+
+    /// Sets every given [`StatData`] under its str id, overwriting existing stats like [`Stats::set_stat_manual`]
+    pub fn extend(&mut self, stats: impl IntoIterator<Item = (String, Box<dyn StatData>)>) {
+        for (stat_id, stat_data) in stats {
+            self.set_stat_manual(&stat_id, stat_data);
+        }
+    }
+
+    /// Merges every stat in other into this collection by adding them.
+    ///
+    /// Stats that don't exist in this collection are created
+    pub fn merge(&mut self, other: Stats) {
+        self.merge_with(other, MergeMode::Add);
+    }
+
+    /// Merges every stat in other into this collection using the given [`MergeMode`] for stats that exist in both.
+    ///
+    /// Stats that don't exist in this collection are created. The [`StatMetadata`] of other is merged as well, it
+    /// replaces existing metadata with [`MergeMode::Overwrite`] and is only attached to stats without metadata
+    /// otherwise
+    pub fn merge_with(&mut self, other: Stats, mode: MergeMode) {
+        for (stat_id, metadata) in other.metadata {
+            let stat_id = self.resolve_alias(&stat_id).into_owned();
+            match mode {
+                MergeMode::Overwrite => {
+                    self.metadata.insert(stat_id, metadata);
+                }
+                MergeMode::Add | MergeMode::KeepExisting => {
+                    self.metadata.entry(stat_id).or_insert(metadata);
+                }
+            }
+        }
+        for (stat_id, stat_data) in other.stats {
+            match mode {
+                MergeMode::Add => self.add_to_stat_manual(&stat_id, stat_data),
+                MergeMode::Overwrite => self.set_stat_manual(&stat_id, stat_data),
+                MergeMode::KeepExisting => {
+                    if !self.contains_stat_manual(&stat_id) {
+                        self.set_stat_manual(&stat_id, stat_data);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Splits this collection into the stats whose id matches the predicate and the rest, moving every
+    /// [`StatData`] without cloning it.
+    ///
+    /// Matching stats take their [`StatMetadata`] with them. Everything else, such as derived stats, aliases, and
+    /// change tracking, stays with the non matching collection. Use [`Stats::merge`] to recombine them
+    pub fn partition(mut self, pred: impl Fn(&str) -> bool) -> (Stats, Stats) {
+        let mut matching = Stats::new();
+        for (stat_id, stat_data) in std::mem::take(&mut self.stats) {
+            if !pred(&stat_id) {
+                self.stats.insert(stat_id, stat_data);
+                continue;
+            }
+            if let Some(metadata) = self.metadata.remove(&stat_id) {
+                matching.metadata.insert(stat_id.clone(), metadata);
+            }
+            if let Some(last_modified) = self.last_modified.as_mut() {
+                last_modified.remove(&stat_id);
+            }
+            matching.stats.insert(stat_id, stat_data);
+        }
+        (matching, self)
+    }
+
+    /// Moves the [`StatData`] under the old str id to the new one, along with its [`StatMetadata`] if the new id has none.
+    ///
+    /// The [`MergeMode`] decides what happens if a stat already exists under the new id. With
+    /// [`MergeMode::KeepExisting`] nothing is moved and the old stat is left in place, as it is with
+    /// [`MergeMode::Add`] when the existing stat is of a different type. Returns true if the stat was moved, false if
+    /// the old id doesn't exist or the rename was skipped.
+    ///
+    /// The observer is notified of the removal of the old id and the modification of the new one
+    pub fn rename_stat_manual(&mut self, old_id: &str, new_id: &str, mode: MergeMode) -> bool {
+        let old_id = self.resolve_alias(old_id).into_owned();
+        let new_id = self.resolve_alias(new_id).into_owned();
+        let (old_id, new_id) = (old_id.as_str(), new_id.as_str());
+        if old_id == new_id {
+            return self.stats.contains_key(old_id);
+        }
+        let (Some(stat), existing) = (self.stats.get(old_id), self.stats.get(new_id)) else {
+            return false;
+        };
+        match (mode, existing) {
+            (MergeMode::KeepExisting, Some(_)) => return false,
+            (MergeMode::Add, Some(existing))
+                if Downcast::as_any(stat.as_ref()).type_id()
+                    != Downcast::as_any(existing.as_ref()).type_id() =>
+            {
+                return false
+            }
+            _ => {}
+        }
+        let Some(stat_data) = self.stats.remove(old_id) else {
+            return false;
+        };
+        if let Some(observer) = self.observer.as_ref() {
+            observer.notify(old_id, ModificationKind::Remove);
+        }
+        if let Some(metadata) = self.metadata.remove(old_id) {
+            self.metadata.entry(new_id.to_string()).or_insert(metadata);
+        }
+        if let Some(last_modified) = self.last_modified.as_mut() {
+            last_modified.remove(old_id);
+        }
+        match mode {
+            MergeMode::Add => self.add_to_stat_manual(new_id, stat_data),
+            MergeMode::Overwrite | MergeMode::KeepExisting => {
+                self.set_stat_manual(new_id, stat_data)
+            }
+        }
+        true
+    }
+
+    /// Moves the [`StatData`] under the old [`StatIdentifier`] to the new one, see [`Stats::rename_stat_manual`]
+    pub fn rename_stat(
+        &mut self,
+        old_id: &impl StatIdentifier,
+        new_id: &impl StatIdentifier,
+        mode: MergeMode,
+    ) -> bool {
+        self.rename_stat_manual(old_id.identifier(), new_id.identifier(), mode)
+    }
+
+    /// Returns every stat that was added, removed, or changed in this collection compared to the previous one, sorted by id.
+    ///
+    /// Changes are detected with [`StatData::eq_dyn`], which defaults to `false` so stats whose type doesn't
+    /// implement it are always reported as [`StatDiffKind::Changed`]
+    pub fn diff(&self, previous: &Stats) -> Vec<StatDiff> {
+        let mut diffs: Vec<StatDiff> = self
+            .iter()
+            .filter_map(|(stat_id, stat)| {
+                let kind = match previous.get_stat_manual(stat_id) {
+                    None => StatDiffKind::Added,
+                    Some(previous_stat) if !stat.as_ref().eq_dyn(previous_stat.as_ref()) => {
+                        StatDiffKind::Changed
+                    }
+                    Some(_) => return None,
+                };
+                Some(StatDiff {
+                    stat_id: stat_id.to_string(),
+                    kind,
+                })
+            })
+            .chain(
+                previous
+                    .ids()
+                    .filter(|stat_id| !self.contains_stat_manual(stat_id))
+                    .map(|stat_id| StatDiff {
+                        stat_id: stat_id.to_string(),
+                        kind: StatDiffKind::Removed,
+                    }),
+            )
+            .collect();
+        diffs.sort_unstable_by(|a, b| a.stat_id.cmp(&b.stat_id));
+        diffs
+    }
+
+    /// Registers a derived stat that is recalculated from this collection by [`Stats::recompute_derived`].
+    ///
+    /// The result is stored under the given [`StatIdentifier`] and can be read like any other stat. Any
+    /// modifications made directly to a derived stat are overwritten on the next recompute.
+    ///
+    /// Derived stats without declared dependencies are recomputed in registration order so a derived stat can read
+    /// any derived stat registered before it. Use [`Stats::register_derived_with_dependencies`] when a derived stat
+    /// reads another derived stat so the order doesn't depend on registration.
+    pub fn register_derived(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        derive: impl Fn(&Stats) -> Box<dyn StatData> + Send + Sync + 'static,
+    ) {
+        self.register_derived_with_dependencies(stat_id, &[], derive);
+    }
+
+    /// Registers a derived stat like [`Stats::register_derived`] along with the ids of the stats it reads.
+    ///
+    /// [`Stats::recompute_derived`] recomputes a derived stat after every derived stat it depends on, so derived
+    /// stats can build on each other over several levels regardless of the order they were registered in
+    pub fn register_derived_with_dependencies(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        dependencies: &[&dyn StatIdentifier],
+        derive: impl Fn(&Stats) -> Box<dyn StatData> + Send + Sync + 'static,
+    ) {
+        self.derived.push(DerivedStat {
+            stat_id: stat_id.identifier().to_string(),
+            dependencies: dependencies
+                .iter()
+                .map(|dependency| dependency.identifier().to_string())
+                .collect(),
+            derive: Arc::new(derive),
+        });
+    }
+
+    /// Recalculates every registered derived stat in dependency order and stores the results.
+    ///
+    /// Returns a [`DerivedCycleError`] without recomputing anything if the declared dependencies form a cycle
+    pub fn recompute_derived(&mut self) -> Result<(), DerivedCycleError> {
+        let derived = self.derived.clone();
+        for index in derived::dependency_order(&derived)? {
+            let derived_stat = &derived[index];
+            let value = derived_stat.compute(self);
+            self.set_stat_manual(derived_stat.stat_id(), value);
+        }
+        Ok(())
+    }
+
+    /// Creates a deep copy of every stat in this collection
+    pub fn snapshot(&self) -> StatsSnapshot {
+        StatsSnapshot {
+            stats: self.stats.clone(),
+        }
+    }
+
+    /// Replaces every stat in this collection with the stats from the given snapshot
+    pub fn restore(&mut self, snapshot: StatsSnapshot) {
+        self.stats = snapshot.stats;
+        if let Some(last_modified) = self.last_modified.as_mut() {
+            let now = Instant::now();
+            last_modified.clear();
+            last_modified.extend(self.stats.keys().map(|stat_id| (stat_id.clone(), now)));
+        }
+    }
+
+    /// Returns an iterator over every stat id and its [`StatData`]
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Box<dyn StatData>)> {
+        self.stats.iter().map(|(id, data)| (id.as_str(), data))
+    }
+
+    /// Returns an iterator over every stat id and a mutable reference to its [`StatData`]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut Box<dyn StatData>)> {
+        self.stats.iter_mut().map(|(id, data)| (id.as_str(), data))
+    }
+
+    /// Returns an iterator over every stat whose id starts with the given prefix.
+    ///
+    /// This allows organizing stats into namespaces such as `"combat/kills/melee"`. The separator is only a
+    /// convention and is not enforced, `"combat"` matches `"combat/kills"` as well as `"combat_time"`.
+    pub fn iter_prefix<'a>(
+        &'a self,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a Box<dyn StatData>)> {
+        self.iter().filter(move |(id, _)| id.starts_with(prefix))
+    }
+
+    /// Adds together every stat whose id starts with the given prefix and that can be downcast into the given type.
+    ///
+    /// Returns `None` if no stats match. See [`Stats::iter_prefix`]
+    pub fn sum_prefix_downcast<Stat: StatData + Clone + 'static>(
+        &self,
+        prefix: &str,
+    ) -> Option<Stat> {
+        let mut matching = self
+            .iter_prefix(prefix)
+            .filter_map(|(_, stat)| stat.downcast_ref::<Stat>());
+        let mut sum = matching.next()?.clone();
+        for stat in matching {
+            sum.add(Box::new(stat.clone()));
+        }
+        Some(sum)
+    }
+
+    /// Applies a modification created by `make_mod` to every stat whose id starts with the given prefix.
+    ///
+    /// Each matching stat gets a fresh modification applied through [`Stats::apply_modification_manual`], so the
+    /// [`RateLimiter`] and change tracking behave the same as modifying the stats one at a time. The order the
+    /// matching stats are modified in is unspecified. See [`Stats::iter_prefix`]
+    pub fn modify_prefix(&mut self, prefix: &str, make_mod: impl Fn() -> ModificationType) {
+        let stat_ids: Vec<String> = self
+            .stats
+            .keys()
+            .filter(|stat_id| stat_id.starts_with(prefix))
+            .cloned()
+            .collect();
+        for stat_id in stat_ids {
+            self.apply_modification_manual(&stat_id, make_mod());
+        }
+    }
+
+    /// Folds every stat that can be downcast into the given type into an accumulator.
+    ///
+    /// Stats of any other type are silently skipped, which makes this a filter as well as a fold. Stats are
+    /// visited in arbitrary order
+    pub fn fold_downcast<Stat: StatData + 'static, Acc>(
+        &self,
+        init: Acc,
+        mut f: impl FnMut(Acc, &str, &Stat) -> Acc,
+    ) -> Acc {
+        self.iter().fold(init, |acc, (stat_id, stat)| {
+            match stat.downcast_ref::<Stat>() {
+                Some(stat) => f(acc, stat_id, stat),
+                None => acc,
+            }
+        })
+    }
+
+    /// Returns an iterator over every stat id contained in this collection
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.stats.keys().map(|id| id.as_str())
+    }
+
+    /// Gets the [`StatData`] for the requested [`StatIdentifier`] as a reflected value
+    #[cfg(feature = "reflect")]
+    pub fn get_stat_reflect(&self, stat_id: &impl StatIdentifier) -> Option<&dyn PartialReflect> {
+        self.get_stat_manual(stat_id.identifier())
+            .map(|stat| stat.as_ref().as_partial_reflect())
+    }
+
+    /// Returns an iterator over every stat id and its [`StatData`] as a reflected value
+    #[cfg(feature = "reflect")]
+    pub fn iter_reflect(&self) -> impl Iterator<Item = (&str, &dyn PartialReflect)> {
+        self.iter()
+            .map(|(id, stat)| (id, stat.as_ref().as_partial_reflect()))
+    }
+}
+
+/// The format version written into every serialized [`Stats`].
+///
+/// Saves written before the version was introduced have no `version` field and load as version `0`, saves from a
+/// newer version fail to load rather than silently losing data
+#[cfg(feature = "serde")]
+pub const CURRENT_STATS_VERSION: u32 = 1;
+
+/// The version of saves written before the `version` field existed
+#[cfg(feature = "serde")]
+fn legacy_stats_version() -> Option<u32> {
+    Some(0)
+}
+
+/// Always writes [`CURRENT_STATS_VERSION`], no matter which version the stats were loaded from
+#[cfg(feature = "serde")]
+fn serialize_stats_version<S: serde::Serializer>(
+    _loaded_version: &Option<u32>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u32(CURRENT_STATS_VERSION)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_stats_version<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u32>, D::Error> {
+    let version = u32::deserialize(deserializer)?;
+    check_stats_version(version).map_err(serde::de::Error::custom)?;
+    Ok(Some(version))
+}
+
+/// Returns an error message if stats saved with the given version can't be loaded
+#[cfg(feature = "serde")]
+pub(crate) fn check_stats_version(version: u32) -> Result<(), String> {
+    if version > CURRENT_STATS_VERSION {
+        return Err(format!(
+            "stats were saved with version {version} but only versions up to {CURRENT_STATS_VERSION} are supported"
+        ));
+    }
+    Ok(())
+}
+
+/// Serializes a map keyed by stat id in id order so the output is deterministic
+#[cfg(feature = "serde")]
+fn serialize_sorted<S: serde::Serializer, V: serde::Serialize>(
+    map: &HashMap<String, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(map.iter().collect::<BTreeMap<_, _>>())
+}
+
+/// Formats every stat as `id: value` on its own line, sorted by id so the output is stable between calls.
+///
+/// The alternate form `{:#}` appends the concrete type of each stat, such as `Gold: 5 (u64)`, see
+/// [`StatData::type_name`]
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut stats: Vec<_> = self.iter().collect();
+        stats.sort_unstable_by_key(|(stat_id, _)| *stat_id);
+        for (index, (stat_id, stat)) in stats.into_iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{stat_id}: {stat:?}")?;
+            if f.alternate() {
+                write!(f, " ({})", stat.as_ref().type_name())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Consumes the collection, yielding every stat id and its [`StatData`] in arbitrary order
+impl IntoIterator for Stats {
+    type Item = (String, Box<dyn StatData>);
+    type IntoIter = bevy::utils::hashbrown::hash_map::IntoIter<String, Box<dyn StatData>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.stats.into_iter()
+    }
+}
+
+impl FromIterator<(String, Box<dyn StatData>)> for Stats {
+    fn from_iter<I: IntoIterator<Item = (String, Box<dyn StatData>)>>(iter: I) -> Self {
+        let mut stats = Stats::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+/// Represents a unique stat
+pub trait StatIdentifier {
+    /// A unique identifier str for this specific stat identifier
+    fn identifier(&self) -> &'static str;
+}
+
+impl StatIdentifier for Box<dyn StatIdentifier> {
+    fn identifier(&self) -> &'static str {
+        self.as_ref().identifier()
+    }
+}
+
+/// Bound used by [`StatData`] to require [`Reflect`] when the `reflect` feature is enabled
+#[cfg(feature = "reflect")]
+pub trait MaybeReflect: Reflect {}
+
+#[cfg(feature = "reflect")]
+impl<T: Reflect> MaybeReflect for T {}
+
+/// Bound used by [`StatData`] to require `Reflect` when the `reflect` feature is enabled
+#[cfg(not(feature = "reflect"))]
+pub trait MaybeReflect {}
+
+#[cfg(not(feature = "reflect"))]
+impl<T> MaybeReflect for T {}
+
+/// A type that can be used as a stat
+///
+/// Must include `#[typetag::serde` on any implementations
+///
+/// With the `reflect` feature implementations must also implement `Reflect` and should be registered with
+/// [`ReflectStatData`] type data, see [`StatAppExt::register_stat_types`]
+#[cfg_attr(feature = "reflect", reflect_trait)]
+#[cfg_attr(feature = "serde", typetag::serde(tag = "type"))]
+pub trait StatData: Downcast + DynClone + Debug + Send + Sync + MaybeReflect {
+    /// Constructs a new boxed [`StatData`]
+    fn new(stat: Self) -> Box<Self>
+    where
+        Self: Sized,
+    {
+        Box::new(stat)
+    }
+    /// Creates a new instance of the same kind of stat data
+    fn default(&self) -> Box<dyn StatData>;
+    /// Adds the given other to this stat data. The built in integer implementations saturate at the bounds of the type
     fn add(&mut self, other: Box<dyn StatData>);
-    /// Subtracts the given other from this stat data
+    /// Subtracts the given other from this stat data. The built in integer implementations saturate at the bounds of
+    /// the type
     fn sub(&mut self, other: Box<dyn StatData>);
+    /// Adds the given percentage of the current value to this stat data. Negative percentages subtract.
+    ///
+    /// Does nothing by default. The built in integer implementations round the change to the nearest whole number
+    /// and saturate at the bounds of the type
+    fn apply_percent(&mut self, _percent: f64) {}
+    /// Flips this stat data between its two states.
+    ///
+    /// Does nothing by default. The built in `bool` implementation negates the value
+    fn toggle(&mut self) {}
+    /// Compares this stat data with the given other.
+    ///
+    /// Returns `None` by default or if the two values are not comparable. The built in numeric and [`Duration`]
+    /// implementations compare against values of the same type
+    ///
+    /// [`Duration`]: std::time::Duration
+    fn compare(&self, _other: &dyn StatData) -> Option<Ordering> {
+        None
+    }
+    /// Returns true if this stat data is equal to the given other.
+    ///
+    /// Returns `false` by default, so types that don't implement it are never considered equal. The built in numeric,
+    /// [`Duration`], [`Timer`], `bool`, and `String` implementations compare against values of the same type
+    ///
+    /// [`Duration`]: std::time::Duration
+    /// [`Timer`]: bevy::time::Timer
+    fn eq_dyn(&self, _other: &dyn StatData) -> bool {
+        false
+    }
+    /// Sets this stat data to the value of the given other in place, returning true if it was accepted.
+    ///
+    /// Returns `false` by default, in which case [`Stats::set_stat_manual`] replaces this stat data with the other.
+    /// The built in [`BoundedStat`](bounded::BoundedStat), [`FloorStat`](floor::FloorStat), and
+    /// [`PeakTrackedStat`](peak::PeakTrackedStat) accept their plain numeric type, keeping their bounds and peaks
+    fn set_from(&mut self, _other: &dyn StatData) -> bool {
+        false
+    }
+    /// Returns the name of the concrete type behind a `dyn StatData`, see [`std::any::type_name`].
+    ///
+    /// Used for diagnostics such as [`StatAccessError`] and the alternate [`Display`] form of [`Stats`]
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
 clone_trait_object!(StatData);
 impl_downcast!(StatData);
 
+// Boxed stat data can't be reflected so this impl is unavailable with the `reflect` feature
+#[cfg(not(feature = "reflect"))]
 #[cfg_attr(feature = "serde", typetag::serde)]
 impl StatData for Box<dyn StatData> {
     fn default(&self) -> Box<dyn StatData> {
@@ -179,27 +1626,42 @@ impl StatData for Box<dyn StatData> {
     fn sub(&mut self, other: Box<dyn StatData>) {
         self.as_mut().sub(other)
     }
+
+    fn apply_percent(&mut self, percent: f64) {
+        self.as_mut().apply_percent(percent)
+    }
+
+    fn toggle(&mut self) {
+        self.as_mut().toggle()
+    }
+
+    fn compare(&self, other: &dyn StatData) -> Option<Ordering> {
+        self.as_ref().compare(other)
+    }
+
+    fn eq_dyn(&self, other: &dyn StatData) -> bool {
+        self.as_ref().eq_dyn(other)
+    }
+
+    fn set_from(&mut self, other: &dyn StatData) -> bool {
+        self.as_mut().set_from(other)
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.as_ref().type_name()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
 
-    use bevy::prelude::{Component, World};
+    use super::*;
+    use bevy::prelude::World;
     use commands::StatEntityCommandsExt;
     #[cfg(feature = "serde")]
     use serde::Serialize;
-    use stat_modification::ModificationType;
-
-    use super::*;
-
-    pub struct EnemiesKilled;
-
-    impl StatIdentifier for EnemiesKilled {
-        fn identifier(&self) -> &'static str {
-            "Enemies Killed"
-        }
-    }
+    use test_utils::{EnemiesKilled, EntityStats, Gold, PlayTime};
 
     #[test]
     fn core_features() {
@@ -233,58 +1695,531 @@ mod tests {
         assert_eq!(*stats.get_stat_downcast::<f32>(&id).unwrap(), 5.3);
     }
 
-    pub struct PlayTime;
+    pub struct BeatenBoss;
 
-    impl StatIdentifier for PlayTime {
+    impl StatIdentifier for BeatenBoss {
         fn identifier(&self) -> &'static str {
-            "Playtime"
+            "Beaten Boss"
         }
     }
 
     #[test]
-    fn duration() {
+    fn bool_flags() {
         let mut stats = Stats::new();
-        let id = PlayTime;
-        let stat_data = StatData::new(Duration::new(5, 0));
+        let id = BeatenBoss;
 
-        stats.add_to_stat(&id, stat_data);
+        stats.add_to_stat(&id, StatData::new(false));
+        assert!(!*stats.get_stat_downcast::<bool>(&id).unwrap());
+        stats.add_to_stat(&id, StatData::new(true));
+        assert!(*stats.get_stat_downcast::<bool>(&id).unwrap());
+        stats.add_to_stat(&id, StatData::new(false));
+        assert!(*stats.get_stat_downcast::<bool>(&id).unwrap());
+
+        stats.sub_from_stat(&id, StatData::new(false));
+        assert!(*stats.get_stat_downcast::<bool>(&id).unwrap());
+        stats.sub_from_stat(&id, StatData::new(true));
+        assert!(!*stats.get_stat_downcast::<bool>(&id).unwrap());
+    }
+
+    pub struct LastItem;
+
+    impl StatIdentifier for LastItem {
+        fn identifier(&self) -> &'static str {
+            "Last Item"
+        }
+    }
+
+    #[test]
+    fn string_stats() {
+        let mut stats = Stats::new();
+        let id = LastItem;
 
+        stats.add_to_stat(&id, StatData::new("Sword".to_string()));
+        stats.add_to_stat(&id, StatData::new(", Shield".to_string()));
+        stats.add_to_stat(&id, StatData::new(", Sword".to_string()));
         assert_eq!(
-            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
-            Duration::new(5, 0)
+            stats.get_stat_downcast::<String>(&id).unwrap(),
+            "Sword, Shield, Sword"
         );
-        stats.add_to_stat(&id, StatData::new(Duration::new(5, 0)));
+
+        stats.sub_from_stat(&id, StatData::new("Sword, ".to_string()));
         assert_eq!(
-            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
-            Duration::new(10, 0)
+            stats.get_stat_downcast::<String>(&id).unwrap(),
+            "Shield, Sword"
         );
-        stats.sub_from_stat(&id, StatData::new(Duration::new(3, 0)));
+        stats.sub_from_stat(&id, StatData::new("Bow".to_string()));
         assert_eq!(
-            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
-            Duration::new(7, 0)
+            stats.get_stat_downcast::<String>(&id).unwrap(),
+            "Shield, Sword"
         );
 
-        // If the stat has already been added then it ignores invalid stat types
-        stats.add_to_stat(&id, StatData::new(5.3f32));
+        stats.reset_stat(&id);
+        assert_eq!(stats.get_stat_downcast::<String>(&id).unwrap(), "");
+    }
+
+    #[test]
+    fn merge() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(5u64));
+
+        let mut other = Stats::new();
+        other.add_to_stat(&EnemiesKilled, StatData::new(3u64));
+        other.add_to_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+
+        stats.merge(other);
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 8);
         assert_eq!(
-            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
-            Duration::new(7, 0)
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::new(5, 0)
+        );
+
+        let other = || {
+            let mut other = Stats::new();
+            other.add_to_stat(&EnemiesKilled, StatData::new(1u64));
+            other.add_to_stat(&BeatenBoss, StatData::new(true));
+            other
+        };
+        stats.merge_with(other(), MergeMode::KeepExisting);
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 8);
+        assert!(*stats.get_stat_downcast::<bool>(&BeatenBoss).unwrap());
+
+        stats.merge_with(other(), MergeMode::Overwrite);
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 1);
+        assert_eq!(stats.len(), 3);
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(5u64));
+
+        let snapshot = stats.snapshot();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(5u64));
+        stats.add_to_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 10);
+
+        stats.restore(snapshot);
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 5);
+        assert!(!stats.contains_stat(&PlayTime));
+    }
+
+    pub struct InventoryValue;
+
+    impl StatIdentifier for InventoryValue {
+        fn identifier(&self) -> &'static str {
+            "Inventory Value"
+        }
+    }
+
+    pub struct NetWorth;
+
+    impl StatIdentifier for NetWorth {
+        fn identifier(&self) -> &'static str {
+            "Net Worth"
+        }
+    }
+
+    #[test]
+    fn derived_stats() {
+        let mut stats = Stats::new();
+        stats.register_derived(&NetWorth, |stats| {
+            let gold = stats.get_stat_downcast::<u64>(&Gold).copied().unwrap_or(0);
+            let inventory = stats
+                .get_stat_downcast::<u64>(&InventoryValue)
+                .copied()
+                .unwrap_or(0);
+            StatData::new(gold + inventory * 2)
+        });
+
+        stats.recompute_derived().unwrap();
+        assert_eq!(*stats.get_stat_downcast::<u64>(&NetWorth).unwrap(), 0);
+
+        stats.add_to_stat(&Gold, StatData::new(10u64));
+        stats.add_to_stat(&InventoryValue, StatData::new(5u64));
+        stats.add_to_stat(&NetWorth, StatData::new(1000u64));
+        stats.recompute_derived().unwrap();
+        assert_eq!(*stats.get_stat_downcast::<u64>(&NetWorth).unwrap(), 20);
+    }
+
+    #[test]
+    fn derived_stat_dependencies() {
+        const STRENGTH: StatId<u64> = StatId::new("Strength");
+        const ATTACK: StatId<u64> = StatId::new("Attack");
+        const DAMAGE: StatId<u64> = StatId::new("Damage");
+        const CRITICAL_DAMAGE: StatId<u64> = StatId::new("Critical Damage");
+        let read =
+            |stats: &Stats, stat_id: &StatId<u64>| stats.get_typed(stat_id).copied().unwrap_or(0);
+
+        // Registered in reverse so registration order alone would read stale values
+        let mut stats = Stats::new();
+        stats.register_derived_with_dependencies(&CRITICAL_DAMAGE, &[&DAMAGE], move |stats| {
+            StatData::new(read(stats, &DAMAGE) * 3)
+        });
+        stats.register_derived_with_dependencies(&DAMAGE, &[&ATTACK], move |stats| {
+            StatData::new(read(stats, &ATTACK) + 5)
+        });
+        stats.register_derived_with_dependencies(&ATTACK, &[&STRENGTH], move |stats| {
+            StatData::new(read(stats, &STRENGTH) * 2)
+        });
+        stats.set_typed(&STRENGTH, 10);
+        stats.recompute_derived().unwrap();
+        assert_eq!(stats.get_typed(&ATTACK), Some(&20));
+        assert_eq!(stats.get_typed(&DAMAGE), Some(&25));
+        assert_eq!(stats.get_typed(&CRITICAL_DAMAGE), Some(&75));
+
+        stats.register_derived_with_dependencies(&STRENGTH, &[&CRITICAL_DAMAGE], move |stats| {
+            StatData::new(read(stats, &CRITICAL_DAMAGE))
+        });
+        let error = stats.recompute_derived().unwrap_err();
+        assert_eq!(
+            error.stat_ids(),
+            ["Attack", "Critical Damage", "Damage", "Strength"]
+        );
+        assert_eq!(stats.get_typed(&STRENGTH), Some(&10));
+    }
+
+    pub struct DistanceTraveled;
+
+    impl StatIdentifier for DistanceTraveled {
+        fn identifier(&self) -> &'static str {
+            "Distance Traveled"
+        }
+    }
+
+    #[test]
+    fn vector_stats() {
+        use bevy::math::{IVec2, Vec3};
+
+        let mut stats = Stats::new();
+        let id = DistanceTraveled;
+
+        stats.add_to_stat(&id, StatData::new(Vec3::new(1.0, 2.0, 3.0)));
+        stats.add_to_stat(&id, StatData::new(Vec3::new(1.0, 1.0, 1.0)));
+        stats.sub_from_stat(&id, StatData::new(Vec3::new(0.5, 0.0, 2.0)));
+        assert_eq!(
+            *stats.get_stat_downcast::<Vec3>(&id).unwrap(),
+            Vec3::new(1.5, 3.0, 2.0)
+        );
+        stats.reset_stat(&id);
+        assert_eq!(*stats.get_stat_downcast::<Vec3>(&id).unwrap(), Vec3::ZERO);
+
+        stats.remove_stat(&id);
+        stats.add_to_stat(&id, StatData::new(IVec2::new(3, -2)));
+        stats.sub_from_stat(&id, StatData::new(IVec2::new(1, 1)));
+        assert_eq!(
+            *stats.get_stat_downcast::<IVec2>(&id).unwrap(),
+            IVec2::new(2, -3)
+        );
+    }
+
+    #[test]
+    fn prefix_queries() {
+        let mut stats = Stats::new();
+        stats.add_to_stat_manual("combat/kills/melee", StatData::new(5u64));
+        stats.add_to_stat_manual("combat/kills/ranged", StatData::new(3u64));
+        stats.add_to_stat_manual("combat/kills/spell", StatData::new(2.5f32));
+        stats.add_to_stat_manual("crafting/items", StatData::new(7u64));
+
+        assert_eq!(stats.iter_prefix("combat/kills/").count(), 3);
+        assert_eq!(stats.iter_prefix("crafting/").count(), 1);
+        assert_eq!(stats.sum_prefix_downcast::<u64>("combat/kills/"), Some(8));
+        assert_eq!(stats.sum_prefix_downcast::<u64>("fishing/"), None);
+
+        stats.modify_prefix("combat/kills/", || ModificationType::add_percent(100.0));
+        assert_eq!(stats.sum_prefix_downcast::<u64>("combat/kills/"), Some(16));
+        assert_eq!(
+            stats
+                .get_stat_manual("combat/kills/spell")
+                .and_then(|stat| stat.downcast_ref::<f32>()),
+            Some(&5.0)
+        );
+        stats.modify_prefix("combat/", ModificationType::reset);
+        assert_eq!(stats.sum_prefix_downcast::<u64>("combat/kills/"), Some(0));
+        assert_eq!(
+            stats
+                .get_stat_manual("crafting/items")
+                .and_then(|stat| stat.downcast_ref::<u64>()),
+            Some(&7)
+        );
+    }
+
+    #[test]
+    fn reset_all() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(5u64));
+        stats.add_to_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+        stats.add_to_stat(&BeatenBoss, StatData::new(true));
+
+        stats.reset_matching(|id| id == "Enemies Killed");
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 0);
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::new(5, 0)
+        );
+
+        stats.reset_all();
+        assert_eq!(stats.len(), 3);
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 0);
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::ZERO
+        );
+        assert!(!*stats.get_stat_downcast::<bool>(&BeatenBoss).unwrap());
+    }
+
+    #[test]
+    fn percent_modifications() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(15u64));
+        stats.apply_modification(&EnemiesKilled, ModificationType::add_percent(10.0));
+        // 1.5 rounds to 2
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 17);
+        stats.apply_modification(&EnemiesKilled, ModificationType::sub_percent(200.0));
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 0);
+
+        stats.add_to_stat(&Gold, StatData::new(-20i32));
+        stats.apply_modification(&Gold, ModificationType::add_percent(50.0));
+        assert_eq!(*stats.get_stat_downcast::<i32>(&Gold).unwrap(), -30);
+
+        stats.add_to_stat(&PlayTime, StatData::new(2.0f32));
+        stats.apply_modification(&PlayTime, ModificationType::sub_percent(25.0));
+        assert_eq!(*stats.get_stat_downcast::<f32>(&PlayTime).unwrap(), 1.5);
+
+        // Durations saturate instead of panicking on overflow or non finite percentages
+        stats.set_stat(&PlayTime, StatData::new(Duration::MAX));
+        stats.apply_modification(&PlayTime, ModificationType::add_percent(10.0));
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::MAX
+        );
+        stats.set_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+        stats.apply_modification(&PlayTime, ModificationType::add_percent(f64::INFINITY));
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::MAX
+        );
+        stats.apply_modification(&PlayTime, ModificationType::sub_percent(f64::INFINITY));
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::ZERO
+        );
+
+        // Stats that don't support percentages or don't exist are left alone, as are stats a percentage doesn't change
+        stats.add_to_stat(&LastItem, StatData::new("Sword".to_string()));
+        assert!(!stats.apply_modification(&LastItem, ModificationType::add_percent(10.0)));
+        assert!(!stats.apply_modification(&NetWorth, ModificationType::add_percent(10.0)));
+        assert!(!stats.apply_modification(&PlayTime, ModificationType::add_percent(0.0)));
+        assert_eq!(
+            stats.get_stat_downcast::<String>(&LastItem).unwrap(),
+            "Sword"
+        );
+        assert!(!stats.contains_stat(&NetWorth));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn export_import_stat() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+
+        let json = stats.export_stat(&PlayTime).unwrap();
+        assert_eq!(stats.export_stat(&EnemiesKilled), None);
+
+        let mut other = Stats::new();
+        other.import_stat(&EnemiesKilled, &json).unwrap();
+        assert_eq!(
+            *other.get_stat_downcast::<Duration>(&EnemiesKilled).unwrap(),
+            Duration::new(5, 0)
+        );
+        assert!(other
+            .import_stat(&EnemiesKilled, "{\"type\":\"NotAStat\"}")
+            .is_err());
+        assert!(other.contains_stat(&EnemiesKilled));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn stats_version() {
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(3u32));
+        assert_eq!(stats.loaded_version(), None);
+
+        let json = serde_json::to_string(&stats).unwrap();
+        assert!(json.contains(&format!("\"version\":{CURRENT_STATS_VERSION}")));
+        let loaded = serde_json::from_str::<Stats>(&json).unwrap();
+        assert_eq!(loaded.loaded_version(), Some(CURRENT_STATS_VERSION));
+        let streamed = Stats::from_reader(json.as_bytes()).unwrap();
+        assert_eq!(streamed.loaded_version(), Some(CURRENT_STATS_VERSION));
+
+        let legacy = json.replace(&format!("\"version\":{CURRENT_STATS_VERSION},"), "");
+        let loaded = serde_json::from_str::<Stats>(&legacy).unwrap();
+        assert_eq!(loaded.loaded_version(), Some(0));
+        assert_eq!(loaded.get_stat_downcast::<u32>(&EnemiesKilled), Some(&3));
+        assert_eq!(
+            Stats::from_reader(legacy.as_bytes())
+                .unwrap()
+                .loaded_version(),
+            Some(0)
         );
+
+        let future = r#"{"version":99,"stats":{}}"#;
+        let err = serde_json::from_str::<Stats>(future).unwrap_err();
+        assert!(err.to_string().contains("version 99"));
+        assert!(Stats::from_reader(future.as_bytes()).is_err());
     }
 
-    #[derive(Component)]
-    pub struct EntityStats {
-        stats: Stats,
+    #[cfg(feature = "ron")]
+    #[test]
+    fn ron_round_trip() {
+        let mut counter = counter::CounterStat::new();
+        counter.increment_by("crit", 42);
+        counter.increment("miss");
+
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(7u64));
+        stats.set_stat(&Gold, Box::new(counter.clone()));
+
+        let ron = stats.to_ron().unwrap();
+        let loaded = Stats::from_ron(&ron).unwrap();
+        assert_eq!(loaded.get_stat_downcast::<u64>(&EnemiesKilled), Some(&7));
+        assert_eq!(
+            loaded.get_stat_downcast::<counter::CounterStat>(&Gold),
+            Some(&counter)
+        );
+        let err = Stats::from_ron("(stats: {\"Gold\": {\"type\": \"NotAStat\", \"value\": 1}})")
+            .unwrap_err();
+        assert!(err.to_string().contains("NotAStat"));
+    }
+
+    #[test]
+    fn collection_introspection() {
+        let mut stats = Stats::new();
+        let id = EnemiesKilled;
+        assert!(stats.is_empty());
+        assert_eq!(stats.len(), 0);
+        assert!(!stats.contains_stat(&id));
+
+        stats.add_to_stat(&id, StatData::new(5u64));
+        assert!(stats.contains_stat(&id));
+        assert!(stats.contains_stat_manual("Enemies Killed"));
+        assert!(!stats.is_empty());
+        assert_eq!(stats.len(), 1);
+
+        stats.add_to_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+        assert_eq!(stats.len(), 2);
+
+        stats.clear();
+        assert!(stats.is_empty());
+        assert!(!stats.contains_stat(&id));
     }
 
-    impl AsMut<Stats> for EntityStats {
-        fn as_mut(&mut self) -> &mut Stats {
-            &mut self.stats
+    #[cfg(feature = "derive")]
+    #[test]
+    fn derive_stat_identifier() {
+        #[derive(StatIdentifier)]
+        struct GoldCollected;
+
+        #[derive(StatIdentifier)]
+        #[stat(id = "Bosses Beaten")]
+        struct BossesBeaten;
+
+        #[derive(StatIdentifier)]
+        enum Crop {
+            Potato,
+            #[stat(id = "Dandelions")]
+            Dandelion,
         }
+
+        assert_eq!(GoldCollected.identifier(), "GoldCollected");
+        assert_eq!(BossesBeaten.identifier(), "Bosses Beaten");
+        assert_eq!(Crop::Potato.identifier(), "Crop::Potato");
+        assert_eq!(Crop::Dandelion.identifier(), "Dandelions");
+    }
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn reflection() {
+        use std::any::TypeId;
+
+        use bevy::{app::App, ecs::reflect::AppTypeRegistry};
+
+        use crate::events::StatAppExt;
+
+        let mut app = App::new();
+        app.register_stat_types();
+
+        let mut stats = Stats::new();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(5u64));
+
+        let reflected = stats.get_stat_reflect(&EnemiesKilled).unwrap();
+        assert_eq!(reflected.try_downcast_ref::<u64>(), Some(&5u64));
+        assert_eq!(stats.iter_reflect().count(), 1);
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let reflect_stat_data = registry
+            .get_type_data::<ReflectStatData>(TypeId::of::<u64>())
+            .unwrap();
+        let value = 3u64;
+        let stat_data = reflect_stat_data.get(&value).unwrap();
+        assert_eq!(stat_data.downcast_ref::<u64>(), Some(&3u64));
+        assert!(registry.contains(TypeId::of::<Stats>()));
+    }
+
+    #[test]
+    fn iteration() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&EnemiesKilled, StatData::new(5u64));
+        stats.add_to_stat(&PlayTime, StatData::new(Duration::new(5, 0)));
+
+        let mut ids = stats.ids().collect::<Vec<&str>>();
+        ids.sort();
+        assert_eq!(ids, vec!["Enemies Killed", "Playtime"]);
+        assert_eq!(stats.iter().count(), 2);
+
+        for (_, data) in stats.iter_mut() {
+            *data = data.default();
+        }
+        assert_eq!(*stats.get_stat_downcast::<u64>(&EnemiesKilled).unwrap(), 0);
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&PlayTime).unwrap(),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn duration() {
+        let mut stats = Stats::new();
+        let id = PlayTime;
+        let stat_data = StatData::new(Duration::new(5, 0));
+
+        stats.add_to_stat(&id, stat_data);
+
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
+            Duration::new(5, 0)
+        );
+        stats.add_to_stat(&id, StatData::new(Duration::new(5, 0)));
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
+            Duration::new(10, 0)
+        );
+        stats.sub_from_stat(&id, StatData::new(Duration::new(3, 0)));
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
+            Duration::new(7, 0)
+        );
+
+        // If the stat has already been added then it ignores invalid stat types
+        stats.add_to_stat(&id, StatData::new(5.3f32));
+        assert_eq!(
+            *stats.get_stat_downcast::<Duration>(&id).unwrap(),
+            Duration::new(7, 0)
+        );
     }
 
     #[derive(Debug, Clone, PartialEq, Eq)]
     #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "reflect", derive(Reflect))]
     pub struct CropsGrownStat {
         map: HashMap<String, u64>,
     }
@@ -381,4 +2316,797 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn change_tracking() {
+        let mut untracked = Stats::new();
+        untracked.add_to_stat(&EnemiesKilled, Box::new(1u32));
+        assert_eq!(untracked.last_modified(&EnemiesKilled), None);
+
+        let mut stats = Stats::with_change_tracking();
+        assert_eq!(stats.last_modified(&EnemiesKilled), None);
+
+        stats.add_to_stat(&EnemiesKilled, Box::new(1u32));
+        let first = stats.last_modified(&EnemiesKilled).unwrap();
+        assert_eq!(stats.last_modified(&PlayTime), None);
+
+        // Data of a different type is ignored so the stat isn't marked as modified
+        stats.add_to_stat(&EnemiesKilled, Box::new(1.0f32));
+        stats.sub_from_stat(&EnemiesKilled, Box::new(1i64));
+        assert_eq!(stats.last_modified(&EnemiesKilled), Some(first));
+
+        stats.set_stat(&PlayTime, Box::new(Duration::from_secs(5)));
+        let second = stats.last_modified(&PlayTime).unwrap();
+        assert!(second >= first);
+
+        stats.reset_stat(&EnemiesKilled);
+        assert!(stats.last_modified(&EnemiesKilled).unwrap() >= first);
+
+        stats.remove_stat(&EnemiesKilled);
+        assert_eq!(stats.last_modified(&EnemiesKilled), None);
+
+        stats.clear();
+        assert_eq!(stats.last_modified(&PlayTime), None);
+    }
+
+    #[test]
+    fn remove_many_and_extend() {
+        let mut stats: Stats = vec![
+            (
+                "Enemies Killed".to_string(),
+                Box::new(5u32) as Box<dyn StatData>,
+            ),
+            ("Playtime".to_string(), Box::new(Duration::from_secs(5))),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(stats.len(), 2);
+
+        stats.extend([(
+            "Enemies Killed".to_string(),
+            Box::new(2u32) as Box<dyn StatData>,
+        )]);
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&2));
+
+        stats.remove_many(["Missing", "AlsoMissing"]);
+        assert_eq!(stats.len(), 2);
+
+        stats.remove_many(["Enemies Killed", "Playtime", "Missing"]);
+        assert!(stats.is_empty());
+    }
+
+    #[test]
+    fn compare_stats() {
+        assert_eq!(5u32.compare(&3u32), Some(Ordering::Greater));
+        assert_eq!(
+            Duration::from_secs(1).compare(&Duration::from_secs(2)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(5u32.compare(&5i32), None);
+        assert_eq!(true.compare(&true), None);
+
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(10u32));
+        assert!(stats.stat_at_least(&EnemiesKilled, &10u32));
+        assert!(!stats.stat_at_least(&EnemiesKilled, &11u32));
+        assert!(!stats.stat_at_least(&EnemiesKilled, &1u64));
+        assert!(!stats.stat_at_least(&PlayTime, &Duration::ZERO));
+    }
+
+    #[test]
+    fn display_string() {
+        let mut stats = Stats::new();
+        assert_eq!(stats.to_display_string(), "");
+
+        stats.set_stat(&PlayTime, Box::new(2u32));
+        stats.set_stat(&EnemiesKilled, Box::new(String::from("Slime")));
+        assert_eq!(
+            stats.to_display_string(),
+            "Enemies Killed: \"Slime\"\nPlaytime: 2"
+        );
+        assert_eq!(
+            format!("{stats:#}"),
+            "Enemies Killed: \"Slime\" (alloc::string::String)\nPlaytime: 2 (u32)"
+        );
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "reflect", derive(Reflect))]
+    pub struct Level(u32);
+
+    #[cfg_attr(feature = "serde", typetag::serde)]
+    impl StatData for Level {
+        fn default(&self) -> Box<dyn StatData> {
+            Box::new(Level(0))
+        }
+
+        fn add(&mut self, other: Box<dyn StatData>) {
+            if let Some(other) = other.downcast_ref::<Level>() {
+                self.0 += other.0;
+            }
+        }
+
+        fn sub(&mut self, other: Box<dyn StatData>) {
+            if let Some(other) = other.downcast_ref::<Level>() {
+                self.0 -= other.0;
+            }
+        }
+
+        fn eq_dyn(&self, other: &dyn StatData) -> bool {
+            other.downcast_ref::<Level>() == Some(self)
+        }
+    }
+
+    #[test]
+    fn diff_stats() {
+        let mut previous = Stats::new();
+        previous.set_stat(&EnemiesKilled, Box::new(Level(1)));
+        previous.set_stat(&PlayTime, Box::new(Level(1)));
+        previous.set_stat(&Gold, Box::new(Level(1)));
+
+        let mut current = Stats::new();
+        current.set_stat(&EnemiesKilled, Box::new(Level(1)));
+        current.set_stat(&PlayTime, Box::new(Level(2)));
+        current.set_stat(&NetWorth, Box::new(Level(1)));
+
+        let diffs = current.diff(&previous);
+        let diffs: Vec<(&str, StatDiffKind)> = diffs
+            .iter()
+            .map(|diff| (diff.stat_id(), diff.kind()))
+            .collect();
+        assert_eq!(
+            diffs,
+            vec![
+                ("Gold", StatDiffKind::Removed),
+                ("Net Worth", StatDiffKind::Added),
+                ("Playtime", StatDiffKind::Changed),
+            ]
+        );
+    }
+
+    #[test]
+    fn get_stat_typed() {
+        let mut stats = Stats::new();
+        assert_eq!(
+            stats.get_stat_typed::<u32>(&EnemiesKilled),
+            Err(StatAccessError::NotFound)
+        );
+
+        stats.set_stat(&EnemiesKilled, Box::new(5u64));
+        assert_eq!(stats.get_stat_typed::<u64>(&EnemiesKilled), Ok(&5));
+        assert_eq!(
+            stats.get_stat_typed::<u32>(&EnemiesKilled),
+            Err(StatAccessError::TypeMismatch {
+                expected: "u32",
+                found: "u64"
+            })
+        );
+    }
+
+    #[test]
+    fn eq_dyn() {
+        assert!(5u32.eq_dyn(&5u32));
+        assert!(!5u32.eq_dyn(&6u32));
+        assert!(!5u32.eq_dyn(&5u64));
+        assert!(Duration::from_secs(1).eq_dyn(&Duration::from_secs(1)));
+        assert!(!Duration::from_secs(1).eq_dyn(&1.0f32));
+        assert!(true.eq_dyn(&true));
+        assert!(!true.eq_dyn(&false));
+        assert!(String::from("Slime").eq_dyn(&String::from("Slime")));
+        assert!(!String::from("Slime").eq_dyn(&String::from("Bat")));
+
+        let boxed: Box<dyn StatData> = Box::new(2.5f64);
+        assert!(boxed.eq_dyn(&2.5f64));
+        assert!(!boxed.eq_dyn(&2.5f32));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deterministic_serialization() {
+        let mut first = Stats::new();
+        let mut second = Stats::new();
+        let ids: Vec<String> = (0..32).map(|index| format!("Stat {index}")).collect();
+        for id in ids.iter() {
+            first.set_stat_manual(id, Box::new(1u32));
+        }
+        for id in ids.iter().rev() {
+            second.set_stat_manual(id, Box::new(1u32));
+        }
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+    }
+
+    pub struct FirstKill;
+
+    impl StatIdentifier for FirstKill {
+        fn identifier(&self) -> &'static str {
+            "First Kill"
+        }
+    }
+
+    #[test]
+    fn optional_stats() {
+        let mut stats = Stats::new();
+        stats.set_stat(&FirstKill, Box::new(None::<Duration>));
+
+        stats.add_to_stat(&FirstKill, Box::new(Duration::from_secs(3)));
+        assert_eq!(
+            stats.get_stat_downcast::<Option<Duration>>(&FirstKill),
+            Some(&Some(Duration::from_secs(3)))
+        );
+
+        stats.add_to_stat(&FirstKill, Box::new(Some(Duration::from_secs(2))));
+        stats.add_to_stat(&FirstKill, Box::new(None::<Duration>));
+        assert_eq!(
+            stats.get_stat_downcast::<Option<Duration>>(&FirstKill),
+            Some(&Some(Duration::from_secs(5)))
+        );
+
+        stats.sub_from_stat(&FirstKill, Box::new(Duration::from_secs(1)));
+        assert_eq!(
+            stats.get_stat_downcast::<Option<Duration>>(&FirstKill),
+            Some(&None)
+        );
+
+        stats.set_stat(&FirstKill, Box::new(Some(4u32)));
+        stats.reset_stat(&FirstKill);
+        assert_eq!(
+            stats.get_stat_downcast::<Option<u32>>(&FirstKill),
+            Some(&None)
+        );
+    }
+
+    #[test]
+    fn get_or_insert_with() {
+        let mut stats = Stats::new();
+        stats
+            .get_or_insert_with(&EnemiesKilled, || Box::new(5u32))
+            .add(Box::new(1u32));
+        stats
+            .get_or_insert_with(&EnemiesKilled, || Box::new(100u32))
+            .add(Box::new(1u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&7));
+
+        // Only the insert is reported, after the stat exists
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let observed = seen.clone();
+        stats.set_observer(Box::new(move |stat_id, kind| {
+            observed.lock().unwrap().push((stat_id.to_string(), kind));
+        }));
+        stats.get_or_insert_with(&EnemiesKilled, || Box::new(100u32));
+        stats.get_or_insert_with(&Gold, || Box::new(1u32));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![("Gold".to_string(), ModificationKind::Custom)]
+        );
+        stats.clear_observer();
+
+        let play_time = stats
+            .get_or_insert_with_manual("Playtime", || Box::new(Duration::ZERO))
+            .downcast_mut::<Duration>()
+            .unwrap();
+        *play_time += Duration::from_secs(2);
+        assert_eq!(
+            stats.get_stat_downcast::<Duration>(&PlayTime),
+            Some(&Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn map_stat_downcast() {
+        let mut stats = Stats::new();
+        assert!(!stats.map_stat_downcast::<u32>(&EnemiesKilled, |value| *value = 1));
+        assert!(!stats.contains_stat(&EnemiesKilled));
+
+        stats.set_stat(&EnemiesKilled, Box::new(3u32));
+        assert!(!stats.map_stat_downcast::<u64>(&EnemiesKilled, |value| *value = 1));
+        assert!(stats.map_stat_downcast::<u32>(&EnemiesKilled, |value| *value *= 3));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&9));
+    }
+
+    #[test]
+    fn custom_modification() {
+        let diminishing_returns = || {
+            ModificationType::custom(|stat| {
+                if let Some(value) = stat.downcast_mut::<f32>() {
+                    *value = value.sqrt();
+                }
+            })
+        };
+
+        let mut stats = Stats::new();
+        assert!(!stats.apply_modification(&PlayTime, diminishing_returns()));
+        assert!(!stats.contains_stat(&PlayTime));
+
+        stats.set_stat(&PlayTime, Box::new(16.0f32));
+        assert!(stats.preview(&PlayTime, &diminishing_returns()).is_none());
+        stats.apply_modification(&PlayTime, diminishing_returns());
+        stats.apply_modification(&PlayTime, diminishing_returns());
+        assert_eq!(stats.get_stat_downcast::<f32>(&PlayTime), Some(&2.0));
+
+        // Clones share the operation, so it only runs once
+        let shared = diminishing_returns();
+        assert!(stats.apply_modification(&PlayTime, shared.clone()));
+        assert!(!stats.apply_modification(&PlayTime, shared));
+        assert_eq!(
+            stats.get_stat_downcast::<f32>(&PlayTime),
+            Some(&2.0f32.sqrt())
+        );
+
+        // Missing stats are created from the default before the operation runs
+        stats.apply_modification(
+            &EnemiesKilled,
+            ModificationType::custom_or_default(5u32, |stat| stat.add(Box::new(3u32))),
+        );
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&3));
+    }
+
+    #[test]
+    fn clone_stats() {
+        let mut original = Stats::new();
+        original.set_stat(&EnemiesKilled, Box::new(5u32));
+        original.set_stat(&PlayTime, Box::new(String::from("Slime")));
+
+        let mut clone = original.clone();
+        clone.add_to_stat(&EnemiesKilled, Box::new(1u32));
+        clone.add_to_stat(&PlayTime, Box::new(String::from(" King")));
+        clone.set_stat(&Gold, Box::new(1u32));
+
+        assert_eq!(original.get_stat_downcast::<u32>(&EnemiesKilled), Some(&5));
+        assert_eq!(
+            original
+                .get_stat_downcast::<String>(&PlayTime)
+                .map(String::as_str),
+            Some("Slime")
+        );
+        assert!(!original.contains_stat(&Gold));
+        assert_eq!(clone.get_stat_downcast::<u32>(&EnemiesKilled), Some(&6));
+    }
+
+    #[test]
+    fn non_zero_stats() {
+        use std::num::NonZeroU32;
+
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(NonZeroU32::new(2).unwrap()));
+        stats.add_to_stat(&EnemiesKilled, Box::new(3u32));
+        stats.add_to_stat(&EnemiesKilled, Box::new(NonZeroU32::new(1).unwrap()));
+        assert_eq!(
+            stats.get_stat_downcast::<NonZeroU32>(&EnemiesKilled),
+            NonZeroU32::new(6).as_ref()
+        );
+
+        stats.sub_from_stat(&EnemiesKilled, Box::new(10u32));
+        assert_eq!(
+            stats.get_stat_downcast::<NonZeroU32>(&EnemiesKilled),
+            Some(&NonZeroU32::MIN)
+        );
+
+        stats.set_stat(&EnemiesKilled, Box::new(NonZeroU32::new(4).unwrap()));
+        stats.reset_stat(&EnemiesKilled);
+        assert_eq!(
+            stats.get_stat_downcast::<NonZeroU32>(&EnemiesKilled),
+            Some(&NonZeroU32::MIN)
+        );
+    }
+
+    #[test]
+    fn clamp_modification() {
+        let mut stats = Stats::new();
+        stats.apply_modification(&EnemiesKilled, ModificationType::clamp(0i32, 10i32));
+        assert!(!stats.contains_stat(&EnemiesKilled));
+
+        stats.set_stat(&EnemiesKilled, Box::new(15i32));
+        stats.apply_modification(&EnemiesKilled, ModificationType::clamp(10i32, 0i32));
+        assert_eq!(stats.get_stat_downcast::<i32>(&EnemiesKilled), Some(&15));
+        stats.apply_modification(&EnemiesKilled, ModificationType::clamp(0i32, 10i32));
+        assert_eq!(stats.get_stat_downcast::<i32>(&EnemiesKilled), Some(&10));
+
+        stats.set_stat(&EnemiesKilled, Box::new(-5i32));
+        stats.apply_modification(&EnemiesKilled, ModificationType::clamp(0i32, 10i32));
+        assert_eq!(stats.get_stat_downcast::<i32>(&EnemiesKilled), Some(&0));
+        stats.apply_modification(&EnemiesKilled, ModificationType::clamp(0u32, 10u32));
+        assert_eq!(stats.get_stat_downcast::<i32>(&EnemiesKilled), Some(&0));
+    }
+
+    #[test]
+    fn rename_stats() {
+        let mut stats = Stats::new();
+        stats.set_stat_manual("Kills", Box::new(5u64));
+        stats.set_metadata_manual("Kills", StatMetadata::new("Kills", "Enemies killed"));
+
+        assert!(stats.rename_stat_manual("Kills", "Enemies Killed", MergeMode::Overwrite));
+        assert!(!stats.contains_stat_manual("Kills"));
+        assert_eq!(stats.get_stat_downcast::<u64>(&EnemiesKilled), Some(&5));
+        assert!(stats.get_metadata(&EnemiesKilled).is_some());
+        assert!(!stats.rename_stat_manual("Kills", "Enemies Killed", MergeMode::Overwrite));
+
+        stats.set_stat(&Gold, Box::new(3u64));
+        assert!(!stats.rename_stat(&Gold, &EnemiesKilled, MergeMode::KeepExisting));
+        assert_eq!(stats.get_stat_downcast::<u64>(&Gold), Some(&3));
+        assert!(stats.rename_stat(&Gold, &EnemiesKilled, MergeMode::Add));
+        assert_eq!(stats.get_stat_downcast::<u64>(&EnemiesKilled), Some(&8));
+        assert!(!stats.contains_stat(&Gold));
+
+        stats.set_stat(&Gold, Box::new(2u32));
+        assert!(!stats.rename_stat(&Gold, &EnemiesKilled, MergeMode::Add));
+        assert_eq!(stats.get_stat_downcast::<u32>(&Gold), Some(&2));
+        assert_eq!(stats.get_stat_downcast::<u64>(&EnemiesKilled), Some(&8));
+
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let observed = seen.clone();
+        stats.set_observer(Box::new(move |stat_id, kind| {
+            observed.lock().unwrap().push((stat_id.to_string(), kind));
+        }));
+        assert!(stats.rename_stat(&Gold, &PlayTime, MergeMode::Overwrite));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ("Gold".to_string(), ModificationKind::Remove),
+                ("Playtime".to_string(), ModificationKind::Set)
+            ]
+        );
+    }
+
+    #[test]
+    fn conditional_modifications() {
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(9u32));
+        let below_max = |stats: &Stats| !stats.stat_at_least(&EnemiesKilled, &10u32);
+
+        assert!(stats.modify_if(&EnemiesKilled, ModificationType::add(1u32), below_max));
+        assert!(!stats.modify_if(&EnemiesKilled, ModificationType::add(1u32), below_max));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&10));
+    }
+
+    #[test]
+    fn preview_modifications() {
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(50u32));
+
+        let preview = stats
+            .preview(&EnemiesKilled, &ModificationType::sub(15u32))
+            .unwrap();
+        assert_eq!(preview.downcast_ref::<u32>(), Some(&35));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&50));
+
+        let preview = stats
+            .preview(&EnemiesKilled, &ModificationType::reset())
+            .unwrap();
+        assert_eq!(preview.downcast_ref::<u32>(), Some(&0));
+        assert!(stats
+            .preview(&EnemiesKilled, &ModificationType::remove())
+            .is_none());
+        assert!(stats.preview(&Gold, &ModificationType::reset()).is_none());
+
+        let preview = stats.preview(&Gold, &ModificationType::add(5u64)).unwrap();
+        assert_eq!(preview.downcast_ref::<u64>(), Some(&5));
+        assert!(!stats.contains_stat(&Gold));
+    }
+
+    #[test]
+    fn stats_capacity() {
+        assert_eq!(Stats::new().capacity(), 0);
+
+        let mut stats = Stats::with_capacity(40);
+        assert!(stats.capacity() >= 40);
+        assert!(stats.is_empty());
+
+        stats.reserve(100);
+        assert!(stats.capacity() >= 100);
+    }
+
+    #[test]
+    fn non_finite_floats() {
+        let mut stats = Stats::new();
+        stats.set_stat(&DistanceTraveled, Box::new(10f32));
+        stats.add_to_stat(&DistanceTraveled, Box::new(f32::NAN));
+        stats.sub_from_stat(&DistanceTraveled, Box::new(f32::INFINITY));
+        assert_eq!(
+            stats.get_stat_downcast::<f32>(&DistanceTraveled),
+            Some(&10f32)
+        );
+
+        stats.apply_percent(&DistanceTraveled, f64::NAN);
+        stats.apply_percent(&DistanceTraveled, f64::INFINITY);
+        stats.apply_percent(&DistanceTraveled, f64::NEG_INFINITY);
+        assert_eq!(
+            stats.get_stat_downcast::<f32>(&DistanceTraveled),
+            Some(&10f32)
+        );
+
+        stats.apply_percent(&DistanceTraveled, 1e300);
+        assert_eq!(
+            stats.get_stat_downcast::<f32>(&DistanceTraveled),
+            Some(&f32::MAX)
+        );
+
+        stats.add_to_stat(&DistanceTraveled, Box::new(f32::MAX));
+        stats.add_to_stat(&DistanceTraveled, Box::new(f32::MAX));
+        assert_eq!(
+            stats.get_stat_downcast::<f32>(&DistanceTraveled),
+            Some(&f32::MAX)
+        );
+    }
+
+    #[test]
+    fn map_stats() {
+        let crops = |contents: &[(&str, u64)]| -> Box<dyn StatData> {
+            Box::new(
+                contents
+                    .iter()
+                    .map(|(crop, amount)| (crop.to_string(), *amount))
+                    .collect::<std::collections::HashMap<String, u64>>(),
+            )
+        };
+        let mut stats = Stats::new();
+        stats.add_to_stat_manual("Crops Grown", crops(&[("Wheat", 5), ("Corn", 2)]));
+        stats.add_to_stat_manual("Crops Grown", crops(&[("Wheat", 1), ("Potato", 4)]));
+        stats.sub_from_stat_manual("Crops Grown", crops(&[("Corn", 10), ("Carrot", 1)]));
+        assert!(stats
+            .get_stat_manual("Crops Grown")
+            .unwrap()
+            .eq_dyn(crops(&[("Wheat", 6), ("Corn", 0), ("Potato", 4)]).as_ref()));
+
+        let mut distances = std::collections::HashMap::<String, f64>::new();
+        distances.insert("Walked".to_string(), 1.5);
+        stats.add_to_stat_manual("Distances", Box::new(distances.clone()));
+        stats.sub_from_stat_manual("Distances", Box::new(distances.clone()));
+        stats.sub_from_stat_manual("Distances", Box::new(distances));
+        assert_eq!(
+            stats
+                .get_stat_manual("Distances")
+                .and_then(|stat| stat.downcast_ref::<std::collections::HashMap<String, f64>>())
+                .and_then(|map| map.get("Walked")),
+            Some(&0.0)
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&stats).unwrap();
+            let loaded: Stats = serde_json::from_str(&json).unwrap();
+            assert!(loaded
+                .get_stat_manual("Crops Grown")
+                .unwrap()
+                .eq_dyn(stats.get_stat_manual("Crops Grown").unwrap().as_ref()));
+        }
+    }
+
+    #[test]
+    fn set_with_type_checks() {
+        let mut stats = Stats::new();
+        assert_eq!(stats.set_same_type(&EnemiesKilled, Box::new(5u64)), Ok(()));
+        assert_eq!(stats.set_same_type(&EnemiesKilled, Box::new(8u64)), Ok(()));
+        assert_eq!(
+            stats.set_same_type(&EnemiesKilled, Box::new(3u32)),
+            Err(StatAccessError::TypeMismatch {
+                expected: "u64",
+                found: "u32"
+            })
+        );
+        assert_eq!(stats.get_stat_downcast::<u64>(&EnemiesKilled), Some(&8));
+
+        stats.force_set(&EnemiesKilled, Box::new(3u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&3));
+        assert_eq!(
+            stats.set_same_type(&EnemiesKilled, Box::new(9u64)),
+            Err(StatAccessError::TypeMismatch {
+                expected: "u32",
+                found: "u64"
+            })
+        );
+    }
+
+    #[test]
+    fn pair_stats() {
+        let mut stats = Stats::new();
+        stats.add_to_stat_manual("Wins Losses", Box::new((3u64, 1u64)));
+        stats.add_to_stat_manual("Wins Losses", Box::new((1u64, 0u64)));
+        stats.sub_from_stat_manual("Wins Losses", Box::new((0u64, 5u64)));
+        stats.add_to_stat_manual("Wins Losses", Box::new((1u32, 1u32)));
+        let wins_losses = |stats: &Stats| {
+            stats
+                .get_stat_manual("Wins Losses")
+                .and_then(|stat| stat.downcast_ref::<(u64, u64)>())
+                .copied()
+        };
+        assert_eq!(wins_losses(&stats), Some((4, 0)));
+
+        stats.reset_stat_manual("Wins Losses");
+        assert_eq!(wins_losses(&stats), Some((0, 0)));
+    }
+
+    #[test]
+    fn partition_stats() {
+        let mut stats = Stats::new();
+        stats.set_stat_manual("net/health", Box::new(10u32));
+        stats.set_stat_manual("net/mana", Box::new(5u32));
+        stats.set_stat(&Gold, Box::new(3u64));
+        stats.set_metadata_manual("net/health", StatMetadata::new("Health", "Hit points"));
+        let mut original_ids: Vec<String> = stats.ids().map(str::to_string).collect();
+        original_ids.sort();
+
+        let (network, mut local) = stats.partition(|stat_id| stat_id.starts_with("net/"));
+        assert_eq!(network.len(), 2);
+        assert!(network.get_metadata_manual("net/health").is_some());
+        assert_eq!(local.len(), 1);
+        assert!(local.contains_stat(&Gold));
+
+        local.merge(network);
+        let mut ids: Vec<String> = local.ids().map(str::to_string).collect();
+        ids.sort();
+        assert_eq!(ids, original_ids);
+        assert_eq!(
+            local.get_metadata_manual("net/health"),
+            Some(&StatMetadata::new("Health", "Hit points"))
+        );
+    }
+
+    #[test]
+    fn take_stats() {
+        let mut first = Stats::new();
+        let mut second = Stats::new();
+        first.set_stat(&Gold, Box::new(10u64));
+
+        let gold = first.take_stat(&Gold).unwrap();
+        second.set_stat(&Gold, gold);
+        assert!(!first.contains_stat(&Gold));
+        assert_eq!(second.get_stat_downcast::<u64>(&Gold), Some(&10));
+        assert!(first.take_stat(&Gold).is_none());
+
+        assert!(second.remove_stat(&Gold));
+        assert!(!second.remove_stat(&Gold));
+    }
+
+    #[test]
+    fn fold_stats() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Gold, Box::new(10u64));
+        stats.set_stat(&InventoryValue, Box::new(25u64));
+        stats.set_stat(&BeatenBoss, Box::new(true));
+        stats.set_stat(&EnemiesKilled, Box::new(3u32));
+
+        let total = stats.fold_downcast::<u64, u64>(0, |total, _, value| total + value);
+        assert_eq!(total, 35);
+        let mut ids = stats.fold_downcast::<u64, Vec<String>>(Vec::new(), |mut ids, stat_id, _| {
+            ids.push(stat_id.to_string());
+            ids
+        });
+        ids.sort();
+        assert_eq!(ids, ["Gold", "Inventory Value"]);
+    }
+
+    #[test]
+    fn observe_modifications() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut stats = Stats::new();
+        let observed = seen.clone();
+        stats.set_observer(Box::new(move |stat_id, kind| {
+            observed.lock().unwrap().push((stat_id.to_string(), kind));
+        }));
+
+        stats.add_to_stat(&EnemiesKilled, Box::new(5u32));
+        stats.apply_modification(&EnemiesKilled, ModificationType::sub(2u32));
+        stats.reset_stat(&EnemiesKilled);
+        assert!(stats.remove_stat(&EnemiesKilled));
+        assert!(!stats.remove_stat(&EnemiesKilled));
+        stats.reset_stat(&PlayTime);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ("Enemies Killed".to_string(), ModificationKind::Add),
+                ("Enemies Killed".to_string(), ModificationKind::Sub),
+                ("Enemies Killed".to_string(), ModificationKind::Reset),
+                ("Enemies Killed".to_string(), ModificationKind::Remove),
+            ]
+        );
+
+        assert!(stats.clear_observer());
+        stats.add_to_stat(&EnemiesKilled, Box::new(5u32));
+        assert_eq!(seen.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn clones_do_not_share_observer() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut template = Stats::new();
+        let observed = seen.clone();
+        template.set_observer(Box::new(move |stat_id, kind| {
+            observed.lock().unwrap().push((stat_id.to_string(), kind));
+        }));
+
+        let mut clone = template.clone();
+        clone.add_to_stat(&EnemiesKilled, Box::new(5u32));
+        clone.apply_modification(&EnemiesKilled, ModificationType::sub(2u32));
+        assert!(clone.remove_stat(&EnemiesKilled));
+        assert!(seen.lock().unwrap().is_empty());
+        assert!(!clone.clear_observer());
+
+        template.add_to_stat(&EnemiesKilled, Box::new(1u32));
+        assert_eq!(seen.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn toggle_stats() {
+        let mut stats = Stats::with_change_tracking();
+        stats.toggle_stat(&BeatenBoss);
+        assert_eq!(stats.get_stat_downcast::<bool>(&BeatenBoss), Some(&true));
+        assert!(stats.last_modified(&BeatenBoss).is_some());
+
+        assert!(stats.apply_modification(&BeatenBoss, ModificationType::toggle()));
+        assert_eq!(stats.get_stat_downcast::<bool>(&BeatenBoss), Some(&false));
+
+        // Toggling a type that doesn't support it leaves the stat unmodified
+        stats.set_stat(&EnemiesKilled, Box::new(3u32));
+        let last_modified = stats.last_modified(&EnemiesKilled);
+        stats.toggle_stat(&EnemiesKilled);
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&3));
+        assert_eq!(stats.last_modified(&EnemiesKilled), last_modified);
+        assert!(!stats.apply_modification(&EnemiesKilled, ModificationType::toggle()));
+    }
+
+    #[test]
+    fn entity_stats() {
+        use bevy::ecs::entity::Entity;
+
+        let mut stats = Stats::new();
+        let target = Entity::from_raw(7);
+        stats.set_stat(&LastItem, Box::new(target));
+        stats.add_to_stat(&LastItem, Box::new(Entity::from_raw(8)));
+        assert_eq!(stats.get_stat_downcast::<Entity>(&LastItem), Some(&target));
+
+        stats.reset_stat(&LastItem);
+        assert_eq!(
+            stats.get_stat_downcast::<Entity>(&LastItem),
+            Some(&Entity::PLACEHOLDER)
+        );
+    }
+
+    #[test]
+    fn timer_stats() {
+        use bevy::time::{Timer, TimerMode};
+
+        let mut stats = Stats::new();
+        stats.set_stat(
+            &PlayTime,
+            Box::new(Timer::from_seconds(2.0, TimerMode::Repeating)),
+        );
+        stats.add_to_stat(&PlayTime, Box::new(Duration::from_millis(2500)));
+        let timer = stats.get_stat_downcast::<Timer>(&PlayTime).unwrap();
+        assert_eq!(timer.elapsed(), Duration::from_millis(500));
+        assert_eq!(timer.times_finished_this_tick(), 1);
+
+        stats.sub_from_stat(&PlayTime, Box::new(Duration::from_secs(1)));
+        let timer = stats.get_stat_downcast::<Timer>(&PlayTime).unwrap();
+        assert_eq!(timer.elapsed(), Duration::ZERO);
+        assert!(!timer.finished());
+        assert_eq!(timer.mode(), TimerMode::Repeating);
+
+        stats.reset_stat(&PlayTime);
+        let timer = stats.get_stat_downcast::<Timer>(&PlayTime).unwrap();
+        assert_eq!(timer.duration(), Duration::ZERO);
+        assert_eq!(timer.mode(), TimerMode::Repeating);
+    }
+
+    #[test]
+    fn set_max_and_min() {
+        let mut stats = Stats::new();
+        stats.apply_modification(&EnemiesKilled, ModificationType::set_max(10u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&10));
+
+        stats.apply_modification(&EnemiesKilled, ModificationType::set_max(5u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&10));
+        stats.apply_modification(&EnemiesKilled, ModificationType::set_max(12u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&12));
+        stats.apply_modification(&EnemiesKilled, ModificationType::set_max(20u64));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&12));
+
+        stats.apply_modification(&EnemiesKilled, ModificationType::set_min(15u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&12));
+        stats.apply_modification(&EnemiesKilled, ModificationType::set_min(3u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&EnemiesKilled), Some(&3));
+    }
 }
@@ -0,0 +1,283 @@
+//! A [`StatData`] that collects a list of values, such as a log of items crafted.
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// A stat backed by a [`Vec`].
+///
+/// - `add` appends every element of the other list
+/// - `sub` removes the first matching element for every element of the other list
+/// - `default` is an empty list
+///
+/// [`StatData`] is implemented for lists of strings and the primitive types. Lists of your own types can be made
+/// into stats by wrapping them in a newtype and using [`impl_list_stat!`](crate::impl_list_stat). With the `serde`
+/// feature this requires `typetag` as a dependency, the same as any other custom [`StatData`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct ListStat<T> {
+    items: Vec<T>,
+}
+
+impl<T> Default for ListStat<T> {
+    fn default() -> Self {
+        Self { items: Vec::new() }
+    }
+}
+
+impl<T: Clone + PartialEq> ListStat<T> {
+    /// Creates a new empty [`ListStat`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [`ListStat`] containing the given items
+    pub fn from_items(items: Vec<T>) -> Self {
+        Self { items }
+    }
+
+    /// Returns the items in this list
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Appends an item to the end of this list
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Returns the number of items in this list
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if this list contains no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Appends every item of other to this list
+    pub fn extend_from(&mut self, other: &ListStat<T>) {
+        self.items.extend(other.items.iter().cloned());
+    }
+
+    /// Removes the first matching item for every item in other
+    pub fn remove_from(&mut self, other: &ListStat<T>) {
+        for item in other.items.iter() {
+            if let Some(index) = self.items.iter().position(|existing| existing == item) {
+                self.items.remove(index);
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __list_stat_items {
+    ($list:ident, $ty:ty) => {
+        fn default(&self) -> ::std::boxed::Box<dyn $crate::StatData> {
+            ::std::boxed::Box::new($list($crate::list::ListStat::<$ty>::new()))
+        }
+
+        fn add(&mut self, other: ::std::boxed::Box<dyn $crate::StatData>) {
+            if let Some(other) = other.downcast_ref::<$list>() {
+                self.0.extend_from(&other.0);
+            }
+        }
+
+        fn sub(&mut self, other: ::std::boxed::Box<dyn $crate::StatData>) {
+            if let Some(other) = other.downcast_ref::<$list>() {
+                self.0.remove_from(&other.0);
+            }
+        }
+    };
+}
+
+/// Implements [`StatData`](crate::StatData) for single field tuple structs wrapping a [`ListStat`].
+///
+/// Lists of your own types need a newtype since the orphan rule doesn't allow implementing
+/// [`StatData`](crate::StatData) on [`ListStat`](crate::list::ListStat) outside of this crate. Adding and subtracting
+/// only accept values of the same newtype. The newtype must be `Clone + Debug`, and be serializable with the `serde`
+/// feature, while the items must be `Clone + PartialEq`.
+///
+/// ```
+/// # use bevy_easy_stats::{impl_list_stat, list::ListStat, StatIdentifier, Stats};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// # #[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+/// struct CraftedItem(String);
+///
+/// #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # #[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+/// struct CraftedItems(ListStat<CraftedItem>);
+///
+/// impl_list_stat!(CraftedItems, CraftedItem);
+/// #
+/// # struct ItemsCrafted;
+/// #
+/// # impl StatIdentifier for ItemsCrafted {
+/// #     fn identifier(&self) -> &'static str {
+/// #         "Items Crafted"
+/// #     }
+/// # }
+///
+/// # fn main() {
+/// let mut stats = Stats::new();
+/// let sword = CraftedItem("Sword".to_string());
+/// let crafted = CraftedItems(ListStat::from_items(vec![sword.clone()]));
+/// stats.add_to_stat(&ItemsCrafted, Box::new(crafted.clone()));
+/// stats.add_to_stat(&ItemsCrafted, Box::new(crafted));
+/// assert_eq!(
+///     stats
+///         .get_stat_downcast::<CraftedItems>(&ItemsCrafted)
+///         .map(|crafted| crafted.0.items()),
+///     Some(&[sword.clone(), sword][..])
+/// );
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! impl_list_stat {
+    ($list:ident, $ty:ty) => {
+        #[typetag::serde]
+        impl $crate::StatData for $list {
+            $crate::__list_stat_items!($list, $ty);
+        }
+    };
+}
+
+/// Implements [`StatData`](crate::StatData) for single field tuple structs wrapping a [`ListStat`].
+///
+/// Lists of your own types need a newtype since the orphan rule doesn't allow implementing
+/// [`StatData`](crate::StatData) on [`ListStat`](crate::list::ListStat) outside of this crate. Adding and subtracting
+/// only accept values of the same newtype. The newtype must be `Clone + Debug`, and be serializable with the `serde`
+/// feature, while the items must be `Clone + PartialEq`.
+///
+/// ```
+/// # use bevy_easy_stats::{impl_list_stat, list::ListStat, StatIdentifier, Stats};
+/// #[derive(Debug, Clone, PartialEq)]
+/// # #[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+/// struct CraftedItem(String);
+///
+/// #[derive(Debug, Clone)]
+/// # #[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+/// struct CraftedItems(ListStat<CraftedItem>);
+///
+/// impl_list_stat!(CraftedItems, CraftedItem);
+/// #
+/// # struct ItemsCrafted;
+/// #
+/// # impl StatIdentifier for ItemsCrafted {
+/// #     fn identifier(&self) -> &'static str {
+/// #         "Items Crafted"
+/// #     }
+/// # }
+///
+/// # fn main() {
+/// let mut stats = Stats::new();
+/// let sword = CraftedItem("Sword".to_string());
+/// let crafted = CraftedItems(ListStat::from_items(vec![sword.clone()]));
+/// stats.add_to_stat(&ItemsCrafted, Box::new(crafted.clone()));
+/// stats.add_to_stat(&ItemsCrafted, Box::new(crafted));
+/// assert_eq!(
+///     stats
+///         .get_stat_downcast::<CraftedItems>(&ItemsCrafted)
+///         .map(|crafted| crafted.0.items()),
+///     Some(&[sword.clone(), sword][..])
+/// );
+/// # }
+/// ```
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! impl_list_stat {
+    ($list:ident, $ty:ty) => {
+        impl $crate::StatData for $list {
+            $crate::__list_stat_items!($list, $ty);
+        }
+    };
+}
+
+macro_rules! impl_builtin_list_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for ListStat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(ListStat::<$ty>::new())
+                }
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    if let Some(other) = other.downcast_ref::<ListStat<$ty>>() {
+                        self.extend_from(other);
+                    }
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    if let Some(other) = other.downcast_ref::<ListStat<$ty>>() {
+                        self.remove_from(other);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_builtin_list_stat!(
+    String => "ListStat<String>",
+    bool => "ListStat<bool>",
+    u64 => "ListStat<u64>",
+    u32 => "ListStat<u32>",
+    u16 => "ListStat<u16>",
+    u8 => "ListStat<u8>",
+    i64 => "ListStat<i64>",
+    i32 => "ListStat<i32>",
+    i16 => "ListStat<i16>",
+    i8 => "ListStat<i8>",
+    f64 => "ListStat<f64>",
+    f32 => "ListStat<f32>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::ItemsCrafted, Stats};
+
+    use super::*;
+
+    fn items(stats: &Stats) -> &[String] {
+        stats
+            .get_stat_downcast::<ListStat<String>>(&ItemsCrafted)
+            .unwrap()
+            .items()
+    }
+
+    #[test]
+    fn list_stat() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(
+            &ItemsCrafted,
+            StatData::new(ListStat::from_items(vec![
+                "Sword".to_string(),
+                "Shield".to_string(),
+            ])),
+        );
+        stats.add_to_stat(
+            &ItemsCrafted,
+            StatData::new(ListStat::from_items(vec!["Sword".to_string()])),
+        );
+        assert_eq!(items(&stats), ["Sword", "Shield", "Sword"]);
+
+        stats.sub_from_stat(
+            &ItemsCrafted,
+            StatData::new(ListStat::from_items(vec![
+                "Sword".to_string(),
+                "Bow".to_string(),
+            ])),
+        );
+        assert_eq!(items(&stats), ["Shield", "Sword"]);
+
+        stats.reset_stat(&ItemsCrafted);
+        assert!(items(&stats).is_empty());
+    }
+}
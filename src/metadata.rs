@@ -0,0 +1,175 @@
+//! Human readable metadata attached to stat ids, such as display names for tooltips.
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::{StatData, StatIdentifier, Stats};
+
+/// Human readable information about a stat
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct StatMetadata {
+    /// The name shown to players
+    pub display_name: String,
+    /// A longer description of what the stat tracks
+    pub description: String,
+    /// The unit the stat is measured in, such as `"seconds"`
+    pub unit: Option<String>,
+    /// The category the stat is grouped under for aggregate queries, such as `"economy"`.
+    ///
+    /// Categories are independent of the stat id, see [`Stats::sum_category_downcast`]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub category: Option<String>,
+}
+
+impl StatMetadata {
+    /// Creates new [`StatMetadata`] without a unit
+    pub fn new(display_name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            display_name: display_name.into(),
+            description: description.into(),
+            unit: None,
+            category: None,
+        }
+    }
+
+    /// Sets the unit the stat is measured in
+    pub fn with_unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Sets the category the stat is grouped under
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+}
+
+impl Stats {
+    /// Attaches the given [`StatMetadata`] to the given str id, replacing any existing metadata.
+    ///
+    /// Metadata can be set before the stat exists, it is kept through resets and only cleared when the stat is removed
+    pub fn set_metadata_manual(&mut self, stat_id: &str, metadata: StatMetadata) {
+        let stat_id = self.resolve_alias(stat_id);
+        self.metadata.insert(stat_id.into_owned(), metadata);
+    }
+
+    /// Attaches the given [`StatMetadata`] to the requested [`StatIdentifier`], see [`Stats::set_metadata_manual`]
+    pub fn set_metadata(&mut self, stat_id: &impl StatIdentifier, metadata: StatMetadata) {
+        self.set_metadata_manual(stat_id.identifier(), metadata);
+    }
+
+    /// Gets the [`StatMetadata`] attached to the given str id
+    pub fn get_metadata_manual(&self, stat_id: &str) -> Option<&StatMetadata> {
+        self.metadata.get(self.resolve_alias(stat_id).as_ref())
+    }
+
+    /// Gets the [`StatMetadata`] attached to the requested [`StatIdentifier`]
+    pub fn get_metadata(&self, stat_id: &impl StatIdentifier) -> Option<&StatMetadata> {
+        self.get_metadata_manual(stat_id.identifier())
+    }
+
+    /// Groups the given str id under the given category, keeping the rest of its [`StatMetadata`].
+    ///
+    /// Creates empty metadata for the stat if it has none
+    pub fn set_category_manual(&mut self, stat_id: &str, category: impl Into<String>) {
+        let stat_id = self.resolve_alias(stat_id).into_owned();
+        self.metadata.entry(stat_id).or_default().category = Some(category.into());
+    }
+
+    /// Groups the requested [`StatIdentifier`] under the given category, see [`Stats::set_category_manual`]
+    pub fn set_category(&mut self, stat_id: &impl StatIdentifier, category: impl Into<String>) {
+        self.set_category_manual(stat_id.identifier(), category);
+    }
+
+    /// Returns an iterator over the ids of every stat grouped under the given category
+    pub fn category_ids<'a>(&'a self, category: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.metadata
+            .iter()
+            .filter(move |(_, metadata)| metadata.category.as_deref() == Some(category))
+            .map(|(stat_id, _)| stat_id.as_str())
+    }
+
+    /// Sums every stat in the given category that downcasts into the given type using [`StatData::add`].
+    ///
+    /// Stats of other types are skipped. Returns `None` if no stat in the category has the given type
+    pub fn sum_category_downcast<Stat: StatData + Clone + 'static>(
+        &self,
+        category: &str,
+    ) -> Option<Stat> {
+        let mut total: Option<Stat> = None;
+        for stat_id in self.category_ids(category) {
+            let Some(value) = self
+                .stats
+                .get(stat_id)
+                .and_then(|stat| stat.downcast_ref::<Stat>())
+            else {
+                continue;
+            };
+            match total.as_mut() {
+                Some(total) => total.add(Box::new(value.clone())),
+                None => total = Some(value.clone()),
+            }
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{Gold, PlayTime};
+
+    use super::*;
+
+    pub struct Kills;
+
+    impl StatIdentifier for Kills {
+        fn identifier(&self) -> &'static str {
+            "Kills"
+        }
+    }
+
+    #[test]
+    fn category_sums() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Gold, Box::new(10u64));
+        stats.set_stat_manual("Bank Gold", Box::new(25u64));
+        stats.set_stat(&Kills, Box::new(4u64));
+        stats.set_stat(&PlayTime, Box::new(2.5f32));
+        stats.set_category(&Gold, "economy");
+        stats.set_category_manual("Bank Gold", "economy");
+        stats.set_category(&Kills, "combat");
+        stats.set_metadata(
+            &PlayTime,
+            StatMetadata::new("Play Time", "Total time played").with_category("economy"),
+        );
+
+        assert_eq!(stats.sum_category_downcast::<u64>("economy"), Some(35));
+        assert_eq!(stats.sum_category_downcast::<u64>("combat"), Some(4));
+        assert_eq!(stats.sum_category_downcast::<f32>("economy"), Some(2.5));
+        assert_eq!(stats.sum_category_downcast::<u64>("social"), None);
+    }
+
+    #[test]
+    fn stat_metadata() {
+        let mut stats = Stats::new();
+        let metadata = StatMetadata::new("Play Time", "Total time played").with_unit("seconds");
+        stats.set_metadata(&PlayTime, metadata.clone());
+        stats.set_stat(&PlayTime, Box::new(5u64));
+
+        stats.reset_stat(&PlayTime);
+        assert_eq!(stats.get_metadata(&PlayTime), Some(&metadata));
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&stats).unwrap();
+            let loaded = serde_json::from_str::<Stats>(&json).unwrap();
+            assert_eq!(loaded.get_metadata(&PlayTime), Some(&metadata));
+        }
+
+        stats.remove_stat(&PlayTime);
+        assert_eq!(stats.get_metadata(&PlayTime), None);
+    }
+}
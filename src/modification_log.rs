@@ -0,0 +1,114 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use bevy::prelude::Resource;
+
+use crate::{
+    stat_modification::{ModificationKind, ModificationType},
+    StatData,
+};
+
+/// A single modification recorded in a [`ModificationLog`]
+#[derive(Debug, Clone)]
+pub struct LoggedModification {
+    stat_id: String,
+    kind: ModificationKind,
+    data: Option<Box<dyn StatData>>,
+}
+
+impl LoggedModification {
+    pub(crate) fn new(stat_id: &str, modification_type: &ModificationType) -> Self {
+        let data = match modification_type {
+            ModificationType::Add(data)
+            | ModificationType::Sub(data)
+            | ModificationType::Set(data)
+            | ModificationType::SetMax(data)
+            | ModificationType::SetMin(data) => Some(data.clone()),
+            _ => None,
+        };
+        Self {
+            stat_id: stat_id.to_string(),
+            kind: modification_type.kind(),
+            data,
+        }
+    }
+
+    /// The id of the stat that was modified
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// The kind of modification that was applied
+    pub fn kind(&self) -> ModificationKind {
+        self.kind
+    }
+
+    /// A copy of the [`StatData`] carried by an add, sub, set, set max, or set min modification
+    pub fn data(&self) -> Option<&dyn StatData> {
+        self.data.as_deref()
+    }
+}
+
+/// A record of every [`ModifyStat`](crate::ModifyStat) event applied to a stat resource, in order.
+///
+/// Enabled with [`StatAppExt::enable_modification_log`](crate::StatAppExt::enable_modification_log). Once the log
+/// reaches its max length the oldest entries are dropped
+#[derive(Resource)]
+pub struct ModificationLog<StatCollection: crate::StatCollection> {
+    entries: VecDeque<LoggedModification>,
+    max_len: usize,
+    pd: PhantomData<StatCollection>,
+}
+
+impl<StatCollection: crate::StatCollection> ModificationLog<StatCollection> {
+    /// Creates a new empty log holding at most `max_len` entries
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(max_len),
+            max_len,
+            pd: PhantomData,
+        }
+    }
+
+    /// Returns the maximum number of entries kept
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    /// Sets the maximum number of entries kept, dropping the oldest entries if there are too many
+    pub fn set_max_len(&mut self, max_len: usize) {
+        self.max_len = max_len;
+        while self.entries.len() > max_len {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Returns the number of entries in the log
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the log has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the entries from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &LoggedModification> {
+        self.entries.iter()
+    }
+
+    /// Removes and returns every entry from oldest to newest
+    pub fn drain(&mut self) -> impl Iterator<Item = LoggedModification> + '_ {
+        self.entries.drain(..)
+    }
+
+    pub(crate) fn push(&mut self, entry: LoggedModification) {
+        if self.max_len == 0 {
+            return;
+        }
+        if self.entries.len() >= self.max_len {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+}
@@ -0,0 +1,229 @@
+//! A [`StatData`] wrapper that layers named additive and multiplicative modifiers over a numeric base value.
+
+use std::time::Duration;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::StatData;
+
+/// A numeric stat with a base value and a stack of named modifiers, such as temporary buffs.
+///
+/// The effective value is `(base + sum of additive modifiers) * product of multiplicative modifiers` and is
+/// recalculated whenever the base or a modifier changes. Modifiers are identified by name so they can be removed
+/// without knowing the amount they applied, adding a modifier with an existing name replaces it.
+///
+/// Adding or subtracting either another [`ModifierStack`] or the plain numeric type changes the base value, the
+/// modifiers of the stored stat are always the ones that are kept. Resetting the stat sets the base to zero and
+/// clears every modifier.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct ModifierStack<T> {
+    base: T,
+    effective: T,
+    additive: Vec<(String, T)>,
+    multiplicative: Vec<(String, f64)>,
+}
+
+impl<T: StatData + Copy> ModifierStack<T> {
+    /// Creates a new [`ModifierStack`] with the given base value and no modifiers
+    pub fn new(base: T) -> Self {
+        Self {
+            base,
+            effective: base,
+            additive: Vec::new(),
+            multiplicative: Vec::new(),
+        }
+    }
+
+    /// Returns the base value without any modifiers applied
+    pub fn base(&self) -> T {
+        self.base
+    }
+
+    /// Returns the value with every modifier applied
+    pub fn effective(&self) -> T {
+        self.effective
+    }
+
+    /// Sets the base value
+    pub fn set_base(&mut self, base: T) {
+        self.base = base;
+        self.recompute();
+    }
+
+    /// Adds a named modifier that is added to the base value, replacing any additive modifier with the same name
+    pub fn add_modifier(&mut self, name: impl Into<String>, value: T) {
+        let name = name.into();
+        self.additive.retain(|(existing, _)| *existing != name);
+        self.additive.push((name, value));
+        self.recompute();
+    }
+
+    /// Removes the named additive modifier. Returns false if it didn't exist
+    pub fn remove_modifier(&mut self, name: &str) -> bool {
+        let len = self.additive.len();
+        self.additive.retain(|(existing, _)| existing != name);
+        self.recompute();
+        self.additive.len() != len
+    }
+
+    /// Adds a named modifier that multiplies the value, replacing any multiplicative modifier with the same name
+    pub fn add_multiplier(&mut self, name: impl Into<String>, multiplier: f64) {
+        let name = name.into();
+        self.multiplicative
+            .retain(|(existing, _)| *existing != name);
+        self.multiplicative.push((name, multiplier));
+        self.recompute();
+    }
+
+    /// Removes the named multiplicative modifier. Returns false if it didn't exist
+    pub fn remove_multiplier(&mut self, name: &str) -> bool {
+        let len = self.multiplicative.len();
+        self.multiplicative.retain(|(existing, _)| existing != name);
+        self.recompute();
+        self.multiplicative.len() != len
+    }
+
+    /// Removes every modifier, leaving only the base value
+    pub fn clear_modifiers(&mut self) {
+        self.additive.clear();
+        self.multiplicative.clear();
+        self.recompute();
+    }
+
+    /// Returns the additive modifiers in the order they were added
+    pub fn additive(&self) -> &[(String, T)] {
+        &self.additive
+    }
+
+    /// Returns the multiplicative modifiers in the order they were added
+    pub fn multiplicative(&self) -> &[(String, f64)] {
+        &self.multiplicative
+    }
+
+    fn recompute(&mut self) {
+        let mut effective = self.base;
+        for (_, value) in self.additive.iter() {
+            StatData::add(&mut effective, Box::new(*value));
+        }
+        let product: f64 = self
+            .multiplicative
+            .iter()
+            .map(|(_, multiplier)| multiplier)
+            .product();
+        StatData::apply_percent(&mut effective, (product - 1.0) * 100.0);
+        self.effective = effective;
+    }
+}
+
+macro_rules! impl_modifier_stack {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for ModifierStack<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    Box::new(ModifierStack::new(<$ty as Default>::default()))
+                }
+
+                fn add(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<ModifierStack<$ty>>() {
+                        other.base
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    StatData::add(&mut self.base, Box::new(other));
+                    self.recompute();
+                }
+
+                fn sub(&mut self, other: Box<dyn StatData>) {
+                    let other = if let Some(other) = other.downcast_ref::<ModifierStack<$ty>>() {
+                        other.base
+                    } else if let Some(other) = other.downcast_ref::<$ty>() {
+                        *other
+                    } else {
+                        return;
+                    };
+                    StatData::sub(&mut self.base, Box::new(other));
+                    self.recompute();
+                }
+
+                fn apply_percent(&mut self, percent: f64) {
+                    StatData::apply_percent(&mut self.base, percent);
+                    self.recompute();
+                }
+            }
+        )*
+    };
+}
+
+impl_modifier_stack!(
+    Duration => "ModifierStack<Duration>",
+    u128 => "ModifierStack<u128>",
+    u64 => "ModifierStack<u64>",
+    u32 => "ModifierStack<u32>",
+    u16 => "ModifierStack<u16>",
+    u8 => "ModifierStack<u8>",
+    f64 => "ModifierStack<f64>",
+    f32 => "ModifierStack<f32>",
+    i128 => "ModifierStack<i128>",
+    i64 => "ModifierStack<i64>",
+    i32 => "ModifierStack<i32>",
+    i16 => "ModifierStack<i16>",
+    i8 => "ModifierStack<i8>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::Attack, Stats};
+
+    use super::*;
+
+    #[test]
+    fn modifier_stack() {
+        let mut attack = ModifierStack::new(10.0f64);
+        attack.set_base(15.0);
+        attack.add_modifier("Sword", 5.0);
+        attack.add_multiplier("Rage", 2.0);
+        attack.add_multiplier("Weakness", 0.5);
+        attack.add_multiplier("Rage", 3.0);
+        assert_eq!(attack.base(), 15.0);
+        assert_eq!(attack.effective(), 30.0);
+
+        assert!(attack.remove_multiplier("Weakness"));
+        assert!(!attack.remove_multiplier("Weakness"));
+        assert_eq!(attack.effective(), 60.0);
+
+        assert!(attack.remove_modifier("Sword"));
+        attack.remove_multiplier("Rage");
+        assert_eq!(attack.effective(), 15.0);
+
+        let mut defense = ModifierStack::new(10u32);
+        defense.add_modifier("Shield", 3);
+        defense.add_multiplier("Armor", 1.5);
+        assert_eq!(defense.effective(), 20);
+
+        let mut stats = Stats::new();
+        attack.add_modifier("Sword", 5.0);
+        stats.set_stat(&Attack, Box::new(attack));
+        stats.add_to_stat(&Attack, Box::new(5.0f64));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<ModifierStack<f64>>(&Attack)
+                .unwrap()
+                .effective(),
+            25.0
+        );
+
+        stats.reset_stat(&Attack);
+        assert_eq!(
+            *stats
+                .get_stat_downcast::<ModifierStack<f64>>(&Attack)
+                .unwrap(),
+            ModifierStack::new(0.0f64)
+        );
+    }
+}
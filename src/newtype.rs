@@ -0,0 +1,192 @@
+//! Macros for turning newtype wrappers around the built in stat types into stats themselves.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __numeric_stat_items {
+    ($ty:ty, $inner:ty) => {
+        fn default(&self) -> ::std::boxed::Box<dyn $crate::StatData> {
+            ::std::boxed::Box::new(<$ty>::from(<$inner as ::std::default::Default>::default()))
+        }
+
+        fn add(&mut self, other: ::std::boxed::Box<dyn $crate::StatData>) {
+            if let Some(other) = other.downcast_ref::<$ty>() {
+                $crate::StatData::add(
+                    &mut self.0,
+                    ::std::boxed::Box::new(::std::clone::Clone::clone(&other.0)),
+                );
+            }
+        }
+
+        fn sub(&mut self, other: ::std::boxed::Box<dyn $crate::StatData>) {
+            if let Some(other) = other.downcast_ref::<$ty>() {
+                $crate::StatData::sub(
+                    &mut self.0,
+                    ::std::boxed::Box::new(::std::clone::Clone::clone(&other.0)),
+                );
+            }
+        }
+
+        fn apply_percent(&mut self, percent: f64) {
+            $crate::StatData::apply_percent(&mut self.0, percent);
+        }
+
+        fn toggle(&mut self) {
+            $crate::StatData::toggle(&mut self.0);
+        }
+
+        fn compare(&self, other: &dyn $crate::StatData) -> Option<::std::cmp::Ordering> {
+            $crate::StatData::compare(&self.0, &other.downcast_ref::<$ty>()?.0)
+        }
+
+        fn eq_dyn(&self, other: &dyn $crate::StatData) -> bool {
+            other
+                .downcast_ref::<$ty>()
+                .is_some_and(|other| $crate::StatData::eq_dyn(&self.0, &other.0))
+        }
+    };
+}
+
+/// Implements [`StatData`](crate::StatData) for single field tuple structs wrapping a built in stat type.
+///
+/// Every operation is forwarded to the inner value so the newtype behaves exactly like the type it wraps, and
+/// only values of the same newtype are accepted. The newtype must implement `From<$inner>` and `Clone + Debug`,
+/// and be serializable with the `serde` feature, which also requires `typetag` as a dependency the same as any
+/// other custom [`StatData`](crate::StatData).
+///
+/// ```
+/// # use bevy_easy_stats::{impl_numeric_stat, StatIdentifier, Stats};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Debug, Clone, Serialize, Deserialize)]
+/// # #[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+/// struct Gold(u64);
+///
+/// impl From<u64> for Gold {
+///     fn from(value: u64) -> Self {
+///         Gold(value)
+///     }
+/// }
+///
+/// impl_numeric_stat!(Gold, u64);
+/// #
+/// # struct PlayerGold;
+/// #
+/// # impl StatIdentifier for PlayerGold {
+/// #     fn identifier(&self) -> &'static str {
+/// #         "Player Gold"
+/// #     }
+/// # }
+///
+/// # fn main() {
+/// let mut stats = Stats::new();
+/// stats.add_to_stat(&PlayerGold, Box::new(Gold(10)));
+/// stats.add_to_stat(&PlayerGold, Box::new(Gold(5)));
+/// assert_eq!(
+///     stats.get_stat_downcast::<Gold>(&PlayerGold).map(|gold| gold.0),
+///     Some(15)
+/// );
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! impl_numeric_stat {
+    ($ty:ident, $inner:ty) => {
+        #[typetag::serde]
+        impl $crate::StatData for $ty {
+            $crate::__numeric_stat_items!($ty, $inner);
+        }
+    };
+}
+
+/// Implements [`StatData`](crate::StatData) for single field tuple structs wrapping a built in stat type.
+///
+/// Every operation is forwarded to the inner value so the newtype behaves exactly like the type it wraps, and
+/// only values of the same newtype are accepted. The newtype must implement `From<$inner>` and `Clone + Debug`,
+/// and be serializable with the `serde` feature, which also requires `typetag` as a dependency the same as any
+/// other custom [`StatData`](crate::StatData).
+///
+/// ```
+/// # use bevy_easy_stats::{impl_numeric_stat, StatIdentifier, Stats};
+/// #[derive(Debug, Clone)]
+/// # #[cfg_attr(feature = "reflect", derive(bevy::reflect::Reflect))]
+/// struct Gold(u64);
+///
+/// impl From<u64> for Gold {
+///     fn from(value: u64) -> Self {
+///         Gold(value)
+///     }
+/// }
+///
+/// impl_numeric_stat!(Gold, u64);
+/// #
+/// # struct PlayerGold;
+/// #
+/// # impl StatIdentifier for PlayerGold {
+/// #     fn identifier(&self) -> &'static str {
+/// #         "Player Gold"
+/// #     }
+/// # }
+///
+/// # fn main() {
+/// let mut stats = Stats::new();
+/// stats.add_to_stat(&PlayerGold, Box::new(Gold(10)));
+/// stats.add_to_stat(&PlayerGold, Box::new(Gold(5)));
+/// assert_eq!(
+///     stats.get_stat_downcast::<Gold>(&PlayerGold).map(|gold| gold.0),
+///     Some(15)
+/// );
+/// # }
+/// ```
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! impl_numeric_stat {
+    ($ty:ident, $inner:ty) => {
+        impl $crate::StatData for $ty {
+            $crate::__numeric_stat_items!($ty, $inner);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "reflect")]
+    use bevy::reflect::Reflect;
+
+    use crate::{test_utils::PlayerGold, Stats};
+
+    #[derive(Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(feature = "reflect", derive(Reflect))]
+    pub struct Gold(u64);
+
+    impl From<u64> for Gold {
+        fn from(value: u64) -> Self {
+            Gold(value)
+        }
+    }
+
+    impl_numeric_stat!(Gold, u64);
+
+    #[test]
+    fn numeric_newtype_stat() {
+        let mut stats = Stats::new();
+        stats.add_to_stat(&PlayerGold, Box::new(Gold(10)));
+        stats.add_to_stat(&PlayerGold, Box::new(5u64));
+        assert_eq!(
+            stats.get_stat_downcast::<Gold>(&PlayerGold),
+            Some(&Gold(10))
+        );
+        stats.sub_from_stat(&PlayerGold, Box::new(Gold(20)));
+        assert_eq!(stats.get_stat_downcast::<Gold>(&PlayerGold), Some(&Gold(0)));
+
+        stats.add_to_stat(&PlayerGold, Box::new(Gold(50)));
+        stats.apply_percent(&PlayerGold, 10.0);
+        assert_eq!(
+            stats.get_stat_downcast::<Gold>(&PlayerGold),
+            Some(&Gold(55))
+        );
+        assert!(stats.stat_at_least(&PlayerGold, &Gold(55)));
+
+        stats.reset_stat(&PlayerGold);
+        assert_eq!(stats.get_stat_downcast::<Gold>(&PlayerGold), Some(&Gold(0)));
+    }
+}
@@ -0,0 +1,305 @@
+//! A single numeric [`StatData`] type and helpers for adding plain numbers to numeric stats.
+
+use std::cmp::Ordering;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::{stat_modification::ModificationKind, StatData, StatIdentifier, Stats};
+
+/// A number stored as either an integer or a float so that every numeric stat can share one concrete type.
+///
+/// Plain numeric stats only combine with their exact type, adding a `u64` to a `u32` stat silently does nothing.
+/// [`Numeric`] accepts every integer and float primitive as well as other [`Numeric`]s:
+///
+/// - integers combine as integers, saturating at the bounds of `i128`
+/// - once a float is involved the result is a float
+/// - `default` is zero of the same kind
+///
+/// Convert primitives with [`From`] and read the value back with [`Numeric::get_as_f64`] or [`Numeric::get_as_i128`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub enum Numeric {
+    /// A whole number
+    Int(i128),
+    /// A floating point number
+    Float(f64),
+}
+
+impl Default for Numeric {
+    fn default() -> Self {
+        Numeric::Int(0)
+    }
+}
+
+impl Numeric {
+    /// Returns the value as an `f64`, large integers lose precision
+    pub fn get_as_f64(&self) -> f64 {
+        match self {
+            Numeric::Int(value) => *value as f64,
+            Numeric::Float(value) => *value,
+        }
+    }
+
+    /// Returns the value as an `i128`, floats are truncated towards zero and saturate at the bounds of `i128`
+    pub fn get_as_i128(&self) -> i128 {
+        match self {
+            Numeric::Int(value) => *value,
+            Numeric::Float(value) => *value as i128,
+        }
+    }
+
+    /// Returns true if the value is a float
+    pub fn is_float(&self) -> bool {
+        matches!(self, Numeric::Float(_))
+    }
+
+    /// Converts a boxed numeric primitive or [`Numeric`] into a [`Numeric`]
+    pub(crate) fn from_stat(stat: &dyn StatData) -> Option<Numeric> {
+        if let Some(numeric) = stat.downcast_ref::<Numeric>() {
+            return Some(*numeric);
+        }
+        macro_rules! from_primitive {
+            ($($ty:ty),*) => {
+                $(
+                    if let Some(value) = stat.downcast_ref::<$ty>() {
+                        return Some(Numeric::from(*value));
+                    }
+                )*
+            };
+        }
+        from_primitive!(u128, u64, u32, u16, u8, i128, i64, i32, i16, i8, f64, f32);
+        None
+    }
+
+    fn combine(self, other: Numeric, negate: bool) -> Numeric {
+        match (self, other) {
+            (Numeric::Int(value), Numeric::Int(other)) if negate => {
+                Numeric::Int(value.saturating_sub(other))
+            }
+            (Numeric::Int(value), Numeric::Int(other)) => Numeric::Int(value.saturating_add(other)),
+            (value, other) if negate => Numeric::Float(value.get_as_f64() - other.get_as_f64()),
+            (value, other) => Numeric::Float(value.get_as_f64() + other.get_as_f64()),
+        }
+    }
+}
+
+macro_rules! numeric_from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Numeric {
+                fn from(value: $ty) -> Self {
+                    Numeric::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+numeric_from_int!(u64, u32, u16, u8, i128, i64, i32, i16, i8);
+
+impl From<u128> for Numeric {
+    /// Values above `i128::MAX` saturate
+    fn from(value: u128) -> Self {
+        Numeric::Int(i128::try_from(value).unwrap_or(i128::MAX))
+    }
+}
+
+impl From<f64> for Numeric {
+    fn from(value: f64) -> Self {
+        Numeric::Float(value)
+    }
+}
+
+impl From<f32> for Numeric {
+    fn from(value: f32) -> Self {
+        Numeric::Float(value as f64)
+    }
+}
+
+#[cfg_attr(feature = "serde", typetag::serde)]
+impl StatData for Numeric {
+    fn default(&self) -> Box<dyn StatData> {
+        match self {
+            Numeric::Int(_) => Box::new(Numeric::Int(0)),
+            Numeric::Float(_) => Box::new(Numeric::Float(0.0)),
+        }
+    }
+
+    fn add(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = Numeric::from_stat(other.as_ref()) {
+            *self = self.combine(other, false);
+        }
+    }
+
+    fn sub(&mut self, other: Box<dyn StatData>) {
+        if let Some(other) = Numeric::from_stat(other.as_ref()) {
+            *self = self.combine(other, true);
+        }
+    }
+
+    fn apply_percent(&mut self, percent: f64) {
+        match self {
+            Numeric::Int(value) => StatData::apply_percent(value, percent),
+            Numeric::Float(value) => StatData::apply_percent(value, percent),
+        }
+    }
+
+    fn compare(&self, other: &dyn StatData) -> Option<Ordering> {
+        match (self, Numeric::from_stat(other)?) {
+            (Numeric::Int(value), Numeric::Int(other)) => Some(value.cmp(&other)),
+            (value, other) => value.get_as_f64().partial_cmp(&other.get_as_f64()),
+        }
+    }
+
+    fn eq_dyn(&self, other: &dyn StatData) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+    }
+}
+
+/// Adds the given value to the stat if it downcasts into one of the given types, returning from the caller if it does.
+///
+/// Integers saturate at the bounds of their type
+macro_rules! add_numeric_as {
+    ($stat:expr, $value:expr, uint: $($uint:ty),*; int: $($int:ty),*; float: $($float:ty),*) => {
+        $(
+            if let Some(stat) = $stat.downcast_mut::<$uint>() {
+                let amount = $value.abs().round() as $uint;
+                if $value >= 0.0 {
+                    *stat = stat.saturating_add(amount);
+                } else {
+                    *stat = stat.saturating_sub(amount);
+                }
+                return true;
+            }
+        )*
+        $(
+            if let Some(stat) = $stat.downcast_mut::<$int>() {
+                // Added as an i128 so values outside the range of the type still saturate at the right bound
+                let value = (*stat as i128).saturating_add($value.round() as i128);
+                *stat = value.clamp(<$int>::MIN as i128, <$int>::MAX as i128) as $int;
+                return true;
+            }
+        )*
+        $(
+            if let Some(stat) = $stat.downcast_mut::<$float>() {
+                StatData::add(stat, Box::new($value as $float));
+                return true;
+            }
+        )*
+    };
+}
+
+pub(crate) fn add_numeric_to(stat: &mut dyn StatData, value: f64) -> bool {
+    if let Some(stat) = stat.downcast_mut::<Numeric>() {
+        let amount = match stat {
+            Numeric::Int(_) => Numeric::Int(value.round() as i128),
+            Numeric::Float(_) => Numeric::Float(value),
+        };
+        *stat = stat.combine(amount, false);
+        return true;
+    }
+    add_numeric_as!(stat, value, uint: u128, u64, u32, u16, u8; int: i128, i64, i32, i16, i8; float: f64, f32);
+    false
+}
+
+impl Stats {
+    /// Adds the given value to the numeric stat under the given str id, converting it into the stored type.
+    ///
+    /// Integers round the value to the nearest whole number and saturate at the bounds of the type, negative values
+    /// subtract. Returns false if the value is NaN or the stat doesn't exist or isn't a primitive integer, float, or
+    /// [`Numeric`].
+    pub fn add_numeric_manual(&mut self, stat_id: &str, value: f64) -> bool {
+        if value.is_nan() {
+            return false;
+        }
+        let stat_id = self.resolve_alias(stat_id);
+        let stat_id = stat_id.as_ref();
+        let Some(stat) = self.stats.get_mut(stat_id) else {
+            return false;
+        };
+        let applied = add_numeric_to(stat.as_mut(), value);
+        if applied {
+            self.mark_modified(stat_id, ModificationKind::Add);
+        }
+        applied
+    }
+
+    /// Adds the given value to the numeric stat for the requested [`StatIdentifier`], converting it into the stored type.
+    ///
+    /// See [`Stats::add_numeric_manual`]
+    pub fn add_numeric(&mut self, stat_id: &impl StatIdentifier, value: f64) -> bool {
+        self.add_numeric_manual(stat_id.identifier(), value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::Gold;
+
+    use super::*;
+
+    #[test]
+    fn add_numeric() {
+        let mut stats = Stats::new();
+        assert!(!stats.add_numeric(&Gold, 1.0));
+
+        stats.set_stat(&Gold, Box::new(10u8));
+        assert!(stats.add_numeric(&Gold, 2.6));
+        assert_eq!(stats.get_stat_downcast::<u8>(&Gold), Some(&13));
+        assert!(stats.add_numeric(&Gold, -20.0));
+        assert_eq!(stats.get_stat_downcast::<u8>(&Gold), Some(&0));
+        assert!(stats.add_numeric(&Gold, 1000.0));
+        assert_eq!(stats.get_stat_downcast::<u8>(&Gold), Some(&255));
+
+        stats.set_stat(&Gold, Box::new(-100i8));
+        assert!(stats.add_numeric(&Gold, -100.0));
+        assert_eq!(stats.get_stat_downcast::<i8>(&Gold), Some(&-128));
+        assert!(stats.add_numeric(&Gold, 1000.0));
+        assert_eq!(stats.get_stat_downcast::<i8>(&Gold), Some(&127));
+
+        stats.set_stat(&Gold, Box::new(1.5f32));
+        assert!(stats.add_numeric(&Gold, -0.5));
+        assert_eq!(stats.get_stat_downcast::<f32>(&Gold), Some(&1.0));
+
+        stats.set_stat(&Gold, Box::new(String::new()));
+        assert!(!stats.add_numeric(&Gold, 1.0));
+    }
+
+    #[test]
+    fn numeric_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Gold, Box::new(Numeric::from(10u32)));
+        stats.add_to_stat(&Gold, Box::new(5u64));
+        stats.sub_from_stat(&Gold, Box::new(Numeric::from(3i8)));
+        let gold = stats.get_stat_downcast::<Numeric>(&Gold).unwrap();
+        assert_eq!(*gold, Numeric::Int(12));
+        assert!(gold.eq_dyn(&12u16));
+        assert_eq!(gold.compare(&12.5f32), Some(Ordering::Less));
+
+        stats.add_to_stat(&Gold, Box::new(0.5f64));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<Numeric>(&Gold)
+                .unwrap()
+                .get_as_f64(),
+            12.5
+        );
+        assert!(stats.add_numeric(&Gold, 1.0));
+        assert_eq!(
+            stats
+                .get_stat_downcast::<Numeric>(&Gold)
+                .unwrap()
+                .get_as_i128(),
+            13
+        );
+
+        stats.reset_stat(&Gold);
+        assert_eq!(
+            stats.get_stat_downcast::<Numeric>(&Gold),
+            Some(&Numeric::Float(0.0))
+        );
+        assert_eq!(Numeric::from(u128::MAX), Numeric::Int(i128::MAX));
+    }
+}
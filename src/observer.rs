@@ -0,0 +1,30 @@
+//! A callback notified of every modification made to a single [`Stats`](crate::Stats) collection.
+
+use std::{fmt::Debug, sync::Mutex};
+
+use crate::stat_modification::ModificationKind;
+
+/// The callback type accepted by [`Stats::set_observer`](crate::Stats::set_observer)
+pub type StatObserverFn = Box<dyn FnMut(&str, ModificationKind) + Send + Sync>;
+
+/// An observer installed on a [`Stats`](crate::Stats). It is not cloned along with the collection
+pub(crate) struct StatObserver(Mutex<StatObserverFn>);
+
+impl StatObserver {
+    pub(crate) fn new(observer: StatObserverFn) -> Self {
+        Self(Mutex::new(observer))
+    }
+
+    /// Calls the observer, skipping it if it panicked during an earlier call
+    pub(crate) fn notify(&self, stat_id: &str, kind: ModificationKind) {
+        if let Ok(mut observer) = self.0.lock() {
+            observer(stat_id, kind);
+        }
+    }
+}
+
+impl Debug for StatObserver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StatObserver")
+    }
+}
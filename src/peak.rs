@@ -0,0 +1,169 @@
+//! A [`StatData`] wrapper that tracks the highest and lowest values a numeric stat has reached.
+
+use std::time::Duration;
+
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+
+use crate::{bounded::wrapped_numeric_stat_ops, StatData};
+
+/// A numeric stat that tracks the highest and lowest values it has ever held.
+///
+/// Adding or subtracting either another [`PeakTrackedStat`] or the plain numeric type is supported. Setting the stat to
+/// the plain numeric type updates the peaks, while setting another [`PeakTrackedStat`] replaces them. Resetting the
+/// stat sets the current value to zero and either keeps or clears the peaks based on `reset_peaks_on_reset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "reflect", derive(Reflect))]
+pub struct PeakTrackedStat<T> {
+    current: T,
+    peak_high: T,
+    peak_low: T,
+    reset_peaks_on_reset: bool,
+}
+
+impl<T: PartialOrd + Copy> PeakTrackedStat<T> {
+    /// Creates a new [`PeakTrackedStat`] with both peaks starting at the given value
+    pub fn new(value: T, reset_peaks_on_reset: bool) -> Self {
+        Self {
+            current: value,
+            peak_high: value,
+            peak_low: value,
+            reset_peaks_on_reset,
+        }
+    }
+
+    /// Returns the current value
+    pub fn current(&self) -> T {
+        self.current
+    }
+
+    /// Returns the highest value this stat has reached
+    pub fn peak_high(&self) -> T {
+        self.peak_high
+    }
+
+    /// Returns the lowest value this stat has reached
+    pub fn peak_low(&self) -> T {
+        self.peak_low
+    }
+
+    /// Returns whether the peaks are cleared when the stat is reset
+    pub fn reset_peaks_on_reset(&self) -> bool {
+        self.reset_peaks_on_reset
+    }
+
+    /// Sets whether the peaks are cleared when the stat is reset
+    pub fn set_reset_peaks_on_reset(&mut self, reset_peaks_on_reset: bool) {
+        self.reset_peaks_on_reset = reset_peaks_on_reset;
+    }
+
+    /// Sets the current value, updating the peaks
+    pub fn set(&mut self, value: T) {
+        self.current = value;
+        self.update_peaks();
+    }
+
+    fn update_peaks(&mut self) {
+        if self.current > self.peak_high {
+            self.peak_high = self.current;
+        }
+        if self.current < self.peak_low {
+            self.peak_low = self.current;
+        }
+    }
+}
+
+macro_rules! impl_peak_tracked_stat {
+    ($($ty:ty => $name:literal),* $(,)?) => {
+        $(
+            #[cfg_attr(feature = "serde", typetag::serde(name = $name))]
+            impl StatData for PeakTrackedStat<$ty> {
+                fn default(&self) -> Box<dyn StatData> {
+                    let zero = <$ty as Default>::default();
+                    if self.reset_peaks_on_reset {
+                        Box::new(PeakTrackedStat::new(zero, self.reset_peaks_on_reset))
+                    } else {
+                        Box::new(PeakTrackedStat {
+                            current: zero,
+                            ..*self
+                        })
+                    }
+                }
+
+                wrapped_numeric_stat_ops!(PeakTrackedStat<$ty>, $ty, current, update_peaks);
+            }
+        )*
+    };
+}
+
+impl_peak_tracked_stat!(
+    Duration => "PeakTrackedStat<Duration>",
+    u128 => "PeakTrackedStat<u128>",
+    u64 => "PeakTrackedStat<u64>",
+    u32 => "PeakTrackedStat<u32>",
+    u16 => "PeakTrackedStat<u16>",
+    u8 => "PeakTrackedStat<u8>",
+    f64 => "PeakTrackedStat<f64>",
+    f32 => "PeakTrackedStat<f32>",
+    i128 => "PeakTrackedStat<i128>",
+    i64 => "PeakTrackedStat<i64>",
+    i32 => "PeakTrackedStat<i32>",
+    i16 => "PeakTrackedStat<i16>",
+    i8 => "PeakTrackedStat<i8>",
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::{stat_modification::ModificationType, test_utils::Score, Stats};
+
+    use super::*;
+
+    #[test]
+    fn peak_tracked_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Score, Box::new(PeakTrackedStat::new(10i32, false)));
+
+        stats.add_to_stat(&Score, Box::new(15i32));
+        stats.sub_from_stat(&Score, Box::new(30i32));
+        stats.add_to_stat(&Score, Box::new(PeakTrackedStat::new(10i32, false)));
+
+        let score = stats
+            .get_stat_downcast::<PeakTrackedStat<i32>>(&Score)
+            .unwrap();
+        assert_eq!(score.current(), 5);
+        assert_eq!(score.peak_high(), 25);
+        assert_eq!(score.peak_low(), -5);
+
+        stats.reset_stat(&Score);
+        let score = stats
+            .get_stat_downcast::<PeakTrackedStat<i32>>(&Score)
+            .unwrap();
+        assert_eq!(score.current(), 0);
+        assert_eq!(score.peak_high(), 25);
+        assert_eq!(score.peak_low(), -5);
+
+        stats.set_stat(&Score, Box::new(PeakTrackedStat::new(10i32, true)));
+        stats.add_to_stat(&Score, Box::new(15i32));
+        stats.reset_stat(&Score);
+        let score = stats
+            .get_stat_downcast::<PeakTrackedStat<i32>>(&Score)
+            .unwrap();
+        assert_eq!(*score, PeakTrackedStat::new(0i32, true));
+    }
+
+    #[test]
+    fn set_peak_tracked_stat() {
+        let mut stats = Stats::new();
+        stats.set_stat(&Score, Box::new(PeakTrackedStat::new(10i32, false)));
+
+        stats.apply_modification(&Score, ModificationType::set(40i32));
+        stats.apply_modification(&Score, ModificationType::set(-5i32));
+        let score = stats
+            .get_stat_downcast::<PeakTrackedStat<i32>>(&Score)
+            .unwrap();
+        assert_eq!(score.current(), -5);
+        assert_eq!(score.peak_high(), 40);
+        assert_eq!(score.peak_low(), -5);
+    }
+}
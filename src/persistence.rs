@@ -0,0 +1,218 @@
+//! Saving a stat resource to a json file in the background and loading it back on startup.
+
+use std::{
+    ffi::OsString,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::{
+    app::{App, PostUpdate},
+    log::{error, warn},
+    prelude::{IntoSystemConfigs, Res, Resource},
+    tasks::{IoTaskPool, TaskPool},
+    time::common_conditions::on_timer,
+};
+
+use crate::{MergeMode, StatSystemSets, Stats};
+
+/// Inserts the `StatCollection` resource loaded from the given path and adds a system that writes it back every interval.
+///
+/// If the file doesn't exist the resource starts empty. Loaded stats are merged over the resources [`Default`] so
+/// derived stats registered there are kept. A file that exists but can't be loaded is moved to its backup path before
+/// anything is saved, if that fails autosave is disabled rather than overwriting it.
+pub(crate) fn add_stat_autosave<StatCollection: crate::StatCollection + Resource + Default>(
+    app: &mut App,
+    path: PathBuf,
+    interval: Duration,
+) {
+    let mut stat_collection = StatCollection::default();
+    match load_stats(&path) {
+        Ok(Some(stats)) => stat_collection
+            .stats_mut()
+            .merge_with(stats, MergeMode::Overwrite),
+        Ok(None) => {}
+        Err(err) => {
+            error!("Failed to load stats from {}: {}", path.display(), err);
+            let backup = with_suffix(&path, ".bak");
+            if let Err(err) = back_up(&path, &backup) {
+                error!(
+                    "Failed to back up {} to {}, autosave is disabled: {}",
+                    path.display(),
+                    backup.display(),
+                    err
+                );
+                app.insert_resource(stat_collection);
+                return;
+            }
+            warn!(
+                "Moved the stats that failed to load to {}",
+                backup.display()
+            );
+        }
+    }
+    app.insert_resource(stat_collection);
+    app.add_systems(
+        PostUpdate,
+        save_stats::<StatCollection>(path)
+            .run_if(on_timer(interval))
+            .after(StatSystemSets::ApplyModifications),
+    );
+}
+
+/// Reads the stats saved at the given path, returning `Ok(None)` if the file doesn't exist
+fn load_stats(path: &Path) -> Result<Option<Stats>, String> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.to_string()),
+    };
+    serde_json::from_str::<Stats>(&json)
+        .map(Some)
+        .map_err(|err| err.to_string())
+}
+
+/// Moves the file at the given path to the backup path, refusing to replace an existing backup
+fn back_up(path: &Path, backup: &Path) -> std::io::Result<()> {
+    if backup.exists() {
+        return Err(std::io::Error::new(
+            ErrorKind::AlreadyExists,
+            "a backup already exists",
+        ));
+    }
+    std::fs::rename(path, backup)
+}
+
+/// Returns the given path with the suffix appended to its file name
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(suffix);
+    PathBuf::from(path)
+}
+
+/// Writes the contents to a temporary file next to the given path and renames it into place, so the file at the path
+/// is always either the previous save or the complete new one
+fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let temp = with_suffix(path, ".tmp");
+    std::fs::write(&temp, contents)?;
+    std::fs::rename(&temp, path)
+}
+
+fn save_stats<StatCollection: AsRef<Stats> + Send + Sync + 'static + Resource>(
+    path: PathBuf,
+) -> impl FnMut(Res<StatCollection>) {
+    // The number of the last save written, shared with the write tasks so they run one at a time and a save that
+    // finishes late never replaces a newer one
+    let last_written = Arc::new(Mutex::new(0u64));
+    let mut next_save = 0u64;
+    move |stat_collection: Res<StatCollection>| {
+        let json = match serde_json::to_string(stat_collection.as_ref().as_ref()) {
+            Ok(json) => json,
+            Err(err) => {
+                error!("Failed to serialize stats for {}: {}", path.display(), err);
+                return;
+            }
+        };
+        next_save += 1;
+        let save = next_save;
+        let path = path.clone();
+        let last_written = last_written.clone();
+        IoTaskPool::get_or_init(TaskPool::new)
+            .spawn(async move {
+                let Ok(mut last_written) = last_written.lock() else {
+                    return;
+                };
+                if *last_written > save {
+                    return;
+                }
+                match write_atomic(&path, &json) {
+                    Ok(()) => *last_written = save,
+                    Err(err) => error!("Failed to save stats to {}: {}", path.display(), err),
+                }
+            })
+            .detach();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{app::App, prelude::Time};
+
+    use crate::{
+        test_utils::{EnemiesKilled, ResourceStats},
+        StatAppExt, Stats,
+    };
+
+    use super::*;
+
+    #[test]
+    fn load_autosave() {
+        let path = std::env::temp_dir().join("bevy_easy_stats_load_autosave.json");
+        let _ = std::fs::remove_file(&path);
+
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_stat_autosave::<ResourceStats>(&path, Duration::from_secs(60));
+        app.update();
+        assert!(app.world().resource::<ResourceStats>().stats.is_empty());
+
+        let mut stats = Stats::new();
+        stats.set_stat(&EnemiesKilled, Box::new(4u32));
+        std::fs::write(&path, serde_json::to_string(&stats).unwrap()).unwrap();
+
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_stat_autosave::<ResourceStats>(&path, Duration::from_secs(60));
+        assert_eq!(
+            app.world()
+                .resource::<ResourceStats>()
+                .stats
+                .get_stat_downcast::<u32>(&EnemiesKilled),
+            Some(&4u32)
+        );
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn back_up_unreadable_autosave() {
+        let path = std::env::temp_dir().join("bevy_easy_stats_back_up_unreadable_autosave.json");
+        let backup = with_suffix(&path, ".bak");
+        let _ = std::fs::remove_file(&backup);
+        std::fs::write(&path, "{ not stats").unwrap();
+
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_stat_autosave::<ResourceStats>(&path, Duration::from_secs(60));
+        assert!(app.world().resource::<ResourceStats>().stats.is_empty());
+        assert!(!path.exists());
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "{ not stats");
+
+        // With the backup taken the next unreadable file is left alone and nothing is saved over it
+        std::fs::write(&path, "{ still not stats").unwrap();
+        let mut app = App::new();
+        app.init_resource::<Time>();
+        app.add_stat_autosave::<ResourceStats>(&path, Duration::ZERO);
+        app.update();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{ still not stats");
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&backup);
+    }
+
+    #[test]
+    fn write_autosave_atomically() {
+        let path = std::env::temp_dir().join("bevy_easy_stats_write_autosave_atomically.json");
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second");
+        assert!(!with_suffix(&path, ".tmp").exists());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
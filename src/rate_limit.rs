@@ -0,0 +1,275 @@
+//! A guard that limits how much a stat can increase within a window of time.
+
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bevy::utils::{hashbrown::HashMap, Instant};
+
+use crate::{
+    numeric::{add_numeric_to, Numeric},
+    stat_modification::ModificationType,
+    StatData, StatIdentifier, Stats,
+};
+
+/// A source of time for a [`RateLimiter`].
+///
+/// Implement this to drive the limiter from game time or from a manual clock in tests
+pub trait StatClock: Debug + Send + Sync {
+    /// Returns the time elapsed since some fixed starting point
+    fn now(&self) -> Duration;
+}
+
+/// A [`StatClock`] using the real time elapsed since it was created
+#[derive(Debug, Clone, Copy)]
+pub struct InstantClock {
+    start: Instant,
+}
+
+impl InstantClock {
+    /// Creates a new clock starting now
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for InstantClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StatClock for InstantClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// A [`StatClock`] that only moves when advanced manually. Clones share the same time
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a new clock starting at zero
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward by the given duration
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap() += duration;
+    }
+}
+
+impl StatClock for ManualClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap()
+    }
+}
+
+/// The maximum amount a stat can increase by within a window of time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// The maximum total increase allowed in each window
+    pub max: f64,
+    /// The length of each window
+    pub window: Duration,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RateWindow {
+    start: Duration,
+    used: f64,
+}
+
+/// Tracks how much each stat increased and clamps the increases that exceed the configured [`RateLimit`].
+///
+/// Windows are fixed, starting at the first increase and resetting once `window` has elapsed. Only stats that are a
+/// numeric primitive or a [`Numeric`] are limited, and the increase is measured by comparing the value before and
+/// after a modification so every [`ModificationType`] counts. Stats without a limit are always allowed. Install one
+/// on a collection with [`Stats::set_rate_limiter`].
+///
+/// Only modifications applied through [`Stats::apply_modification`], including the ones sent as
+/// [`ModifyStat`](crate::events::ModifyStat) events or commands, are limited. The direct methods such as
+/// [`Stats::add_to_stat`], [`Stats::set_stat`], [`Stats::add_numeric`] and [`Stats::map_stat_downcast`] bypass the
+/// limiter entirely
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    clock: Arc<dyn StatClock>,
+    limits: HashMap<String, RateLimit>,
+    windows: HashMap<String, RateWindow>,
+}
+
+impl RateLimiter {
+    /// Creates a new [`RateLimiter`] using an [`InstantClock`]
+    pub fn new() -> Self {
+        Self::with_clock(InstantClock::new())
+    }
+
+    /// Creates a new [`RateLimiter`] using the given [`StatClock`]
+    pub fn with_clock(clock: impl StatClock + 'static) -> Self {
+        Self {
+            clock: Arc::new(clock),
+            limits: HashMap::default(),
+            windows: HashMap::default(),
+        }
+    }
+
+    /// Limits the given str id to the given [`RateLimit`]
+    pub fn limit_manual(&mut self, stat_id: &str, limit: RateLimit) -> &mut Self {
+        self.limits.insert(stat_id.to_string(), limit);
+        self.windows.remove(stat_id);
+        self
+    }
+
+    /// Limits the requested [`StatIdentifier`] to increasing by at most `max` every `window`
+    pub fn limit(
+        &mut self,
+        stat_id: &impl StatIdentifier,
+        max: f64,
+        window: Duration,
+    ) -> &mut Self {
+        self.limit_manual(stat_id.identifier(), RateLimit { max, window })
+    }
+
+    /// Returns true if the given str id has a [`RateLimit`]
+    pub fn is_limited(&self, stat_id: &str) -> bool {
+        self.limits.contains_key(stat_id)
+    }
+
+    /// Records an increase to the given str id and returns how much of it is allowed.
+    ///
+    /// The allowed amount is whatever is left of the current window's limit, or the whole increase if the stat has no
+    /// limit
+    pub fn consume(&mut self, stat_id: &str, increase: f64) -> f64 {
+        let Some(limit) = self.limits.get(stat_id) else {
+            return increase;
+        };
+        let now = self.clock.now();
+        let window = self
+            .windows
+            .entry(stat_id.to_string())
+            .or_insert(RateWindow {
+                start: now,
+                used: 0.0,
+            });
+        if now.saturating_sub(window.start) >= limit.window {
+            *window = RateWindow {
+                start: now,
+                used: 0.0,
+            };
+        }
+        let allowed = increase.min(limit.max - window.used).max(0.0);
+        window.used += allowed;
+        allowed
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stats {
+    /// Applies the given [`ModificationType`] to a stat limited by the [`RateLimiter`], undoing the part of the
+    /// increase that exceeds the limit.
+    ///
+    /// The observer and change tracking are muted while the modification is applied so the stat is only marked as
+    /// modified if it ended up different from before
+    pub(crate) fn apply_rate_limited_manual(
+        &mut self,
+        stat_id: &str,
+        modification_type: ModificationType,
+        previous: Option<&dyn StatData>,
+    ) {
+        let kind = modification_type.reported_kind();
+        let observer = self.observer.take();
+        let last_modified = self.last_modified.take();
+        self.apply_unlimited_manual(stat_id, modification_type);
+        self.observer = observer;
+        self.last_modified = last_modified;
+
+        let previous_value = match previous {
+            Some(previous) => Numeric::from_stat(previous).map(|value| value.get_as_f64()),
+            None => Some(0.0),
+        };
+        let value = self
+            .stats
+            .get(stat_id)
+            .and_then(|stat| Numeric::from_stat(stat.as_ref()))
+            .map(|value| value.get_as_f64());
+        if let (Some(previous_value), Some(value), Some(rate_limiter)) =
+            (previous_value, value, self.rate_limiter.as_mut())
+        {
+            let increase = value - previous_value;
+            if increase > 0.0 {
+                let allowed = rate_limiter.consume(stat_id, increase);
+                if allowed <= 0.0 {
+                    match previous {
+                        Some(previous) => {
+                            self.stats
+                                .insert(stat_id.to_string(), dyn_clone::clone_box(previous));
+                        }
+                        None => {
+                            self.stats.remove(stat_id);
+                        }
+                    }
+                } else if allowed < increase {
+                    if let Some(stat) = self.stats.get_mut(stat_id) {
+                        add_numeric_to(stat.as_mut(), allowed - increase);
+                    }
+                }
+            }
+        }
+
+        if self.stat_changed(stat_id, previous) {
+            self.mark_modified(stat_id, kind);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{stat_modification::ModificationType, test_utils::Score, Stats};
+
+    use super::*;
+
+    #[test]
+    fn rate_limited_stats() {
+        let clock = ManualClock::new();
+        let mut rate_limiter = RateLimiter::with_clock(clock.clone());
+        rate_limiter.limit(&Score, 25.0, Duration::from_secs(1));
+
+        let mut stats = Stats::with_change_tracking();
+        stats.set_rate_limiter(rate_limiter);
+        for _ in 0..3 {
+            stats.apply_modification(&Score, ModificationType::add(10u32));
+        }
+        assert_eq!(stats.get_stat_downcast::<u32>(&Score), Some(&25));
+
+        stats.apply_modification(&Score, ModificationType::sub(5u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&Score), Some(&20));
+
+        // Every kind of modification counts against the limit, rejected ones don't mark the stat as modified
+        let last_modified = stats.last_modified(&Score);
+        stats.apply_modification(&Score, ModificationType::set(100u32));
+        stats.apply_modification(&Score, ModificationType::add_percent(50.0));
+        assert_eq!(stats.get_stat_downcast::<u32>(&Score), Some(&20));
+        assert_eq!(stats.last_modified(&Score), last_modified);
+
+        clock.advance(Duration::from_millis(999));
+        stats.apply_modification(&Score, ModificationType::add(10u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&Score), Some(&20));
+
+        clock.advance(Duration::from_millis(1));
+        stats.apply_modification(&Score, ModificationType::set(100u32));
+        assert_eq!(stats.get_stat_downcast::<u32>(&Score), Some(&45));
+    }
+}
@@ -0,0 +1,117 @@
+use std::{fmt::Display, sync::OnceLock};
+
+use serde::{
+    de::{self, value::MapDeserializer},
+    Deserialize,
+};
+
+use crate::{StatData, Stats};
+
+/// Returns the typetag tags of every [`StatData`] implementation registered with `#[typetag::serde]`, sorted.
+///
+/// This includes the built in implementations and any in the users crate. A save file containing a tag that isn't
+/// listed will fail to deserialize, usually because the implementation is missing its `#[typetag::serde]` attribute
+pub fn registered_stat_types() -> &'static [&'static str] {
+    static REGISTERED: OnceLock<Vec<&'static str>> = OnceLock::new();
+    REGISTERED.get_or_init(|| {
+        // Typetag only exposes its registry through the unknown variant error so look up a tag that can't exist
+        let probe = MapDeserializer::<_, ProbeError>::new(std::iter::once((
+            "type",
+            "bevy_easy_stats::registry::probe",
+        )));
+        let mut tags = match Box::<dyn StatData>::deserialize(probe) {
+            Err(ProbeError::UnknownVariant(tags)) => tags.to_vec(),
+            _ => Vec::new(),
+        };
+        tags.sort_unstable();
+        tags
+    })
+}
+
+/// Returns true if the given typetag tag belongs to a registered [`StatData`] implementation, see [`registered_stat_types`]
+pub fn is_registered_stat_type(tag: &str) -> bool {
+    registered_stat_types().binary_search(&tag).is_ok()
+}
+
+impl Stats {
+    /// Checks that every stat in this collection survives being saved and loaded on its own.
+    ///
+    /// Each stat is serialized to json in isolation and deserialized again, so a failure points at the exact stat
+    /// rather than failing the whole save. This catches values that can't be represented, such as a `NaN` float, and
+    /// custom implementations whose serde impls don't round trip. Returns a message naming the id, type, and error of
+    /// every failing stat, sorted by id. Intended for tests rather than running before every save
+    pub fn validate_serializable(&self) -> Result<(), Vec<String>> {
+        let mut failures: Vec<(&str, String)> = self
+            .iter()
+            .filter_map(|(stat_id, stat)| {
+                let result = serde_json::to_string(stat.as_ref())
+                    .and_then(|json| serde_json::from_str::<Box<dyn StatData>>(&json));
+                let error = result.err()?;
+                Some((
+                    stat_id,
+                    format!("{stat_id} ({}): {error}", stat.type_name()),
+                ))
+            })
+            .collect();
+        if failures.is_empty() {
+            return Ok(());
+        }
+        failures.sort_unstable_by(|a, b| a.0.cmp(b.0));
+        Err(failures.into_iter().map(|(_, failure)| failure).collect())
+    }
+}
+
+#[derive(Debug)]
+enum ProbeError {
+    UnknownVariant(&'static [&'static str]),
+    Other(String),
+}
+
+impl Display for ProbeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProbeError::UnknownVariant(tags) => write!(f, "unknown variant, expected {tags:?}"),
+            ProbeError::Other(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+impl de::Error for ProbeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        ProbeError::Other(msg.to_string())
+    }
+
+    fn unknown_variant(_variant: &str, expected: &'static [&'static str]) -> Self {
+        ProbeError::UnknownVariant(expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_stat_types() {
+        let tags = super::registered_stat_types();
+        assert!(tags.contains(&"u32"));
+        assert!(tags.contains(&"CounterStat"));
+        assert!(tags.contains(&"BoundedStat<u32>"));
+        assert!(is_registered_stat_type("Duration"));
+        assert!(!is_registered_stat_type("NotAStat"));
+    }
+
+    #[test]
+    fn validate_serializable() {
+        let mut stats = Stats::new();
+        stats.set_stat_manual("Gold", Box::new(5u64));
+        stats.set_stat_manual("Distance", Box::new(2.5f64));
+        assert_eq!(stats.validate_serializable(), Ok(()));
+
+        stats.set_stat_manual("Broken", Box::new(f64::NAN));
+        let failures = stats.validate_serializable().unwrap_err();
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].starts_with("Broken (f64)"));
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use crate::{StatData, StatIdentifier, Stats};
+
+/// A [`StatIdentifier`] that also knows the type of [`StatData`] stored under it
+pub trait TypedStatIdentifier: StatIdentifier {
+    /// The [`StatData`] stored under this identifier
+    type Data: StatData;
+}
+
+/// A typed stat key, letting [`Stats::get_typed`] and friends infer the [`StatData`] type from the id.
+///
+/// ```
+/// # use bevy_easy_stats::{StatId, Stats};
+/// const ENEMIES_KILLED: StatId<u64> = StatId::new("Enemies Killed");
+///
+/// let mut stats = Stats::new();
+/// stats.add_typed(&ENEMIES_KILLED, 5);
+/// let killed: Option<&u64> = stats.get_typed(&ENEMIES_KILLED);
+/// assert_eq!(killed, Some(&5));
+/// ```
+pub struct StatId<T> {
+    id: &'static str,
+    pd: PhantomData<fn() -> T>,
+}
+
+impl<T> StatId<T> {
+    /// Creates a new typed stat id
+    pub const fn new(id: &'static str) -> Self {
+        Self {
+            id,
+            pd: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for StatId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for StatId<T> {}
+
+impl<T> Debug for StatId<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("StatId").field(&self.id).finish()
+    }
+}
+
+impl<T> StatIdentifier for StatId<T> {
+    fn identifier(&self) -> &'static str {
+        self.id
+    }
+}
+
+impl<T: StatData> TypedStatIdentifier for StatId<T> {
+    type Data = T;
+}
+
+impl Stats {
+    /// Gets the [`StatData`] for the requested [`TypedStatIdentifier`] as its concrete type
+    pub fn get_typed<Id: TypedStatIdentifier>(&self, stat_id: &Id) -> Option<&Id::Data> {
+        self.get_stat_downcast::<Id::Data>(stat_id)
+    }
+
+    /// Adds the given data to the requested [`TypedStatIdentifier`].
+    ///
+    /// Creates the entry if it doesnt exist
+    pub fn add_typed<Id: TypedStatIdentifier>(&mut self, stat_id: &Id, stat_data: Id::Data) {
+        self.add_to_stat_manual(stat_id.identifier(), Box::new(stat_data))
+    }
+
+    /// Subs the given data from the requested [`TypedStatIdentifier`].
+    ///
+    /// Creates the entry if it doesnt exist
+    pub fn sub_typed<Id: TypedStatIdentifier>(&mut self, stat_id: &Id, stat_data: Id::Data) {
+        self.sub_from_stat_manual(stat_id.identifier(), Box::new(stat_data))
+    }
+
+    /// Sets the given data to the requested [`TypedStatIdentifier`].
+    ///
+    /// Creates the entry if it doesnt exist
+    pub fn set_typed<Id: TypedStatIdentifier>(&mut self, stat_id: &Id, stat_data: Id::Data) {
+        self.set_stat_manual(stat_id.identifier(), Box::new(stat_data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    const ENEMIES_KILLED: StatId<u64> = StatId::new("Enemies Killed");
+    const PLAY_TIME: StatId<Duration> = StatId::new("Playtime");
+
+    #[test]
+    fn typed_stat_ids() {
+        let mut stats = Stats::new();
+        stats.add_typed(&ENEMIES_KILLED, 5);
+        stats.add_typed(&ENEMIES_KILLED, 3);
+        stats.sub_typed(&ENEMIES_KILLED, 1);
+        assert_eq!(stats.get_typed(&ENEMIES_KILLED), Some(&7));
+
+        stats.set_typed(&PLAY_TIME, Duration::new(5, 0));
+        assert_eq!(stats.get_typed(&PLAY_TIME), Some(&Duration::new(5, 0)));
+
+        // Typed ids still work with the untyped api
+        assert_eq!(stats.get_stat_downcast::<u64>(&ENEMIES_KILLED), Some(&7));
+    }
+}
@@ -1,6 +1,54 @@
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+
 use crate::StatData;
 
+/// The function run by a [`ModificationType::Custom`]
+pub type CustomFn = dyn FnOnce(&mut Box<dyn StatData>) + Send + Sync;
+
+/// A game specific operation run once on a stat by a [`ModificationType::Custom`].
+///
+/// Clones share the operation so that modifications stay cloneable for events, it only runs for the first clone
+/// that is applied
+#[derive(Clone)]
+pub struct CustomModification {
+    operation: Arc<Mutex<Option<Box<CustomFn>>>>,
+    default: Option<Box<dyn StatData>>,
+}
+
+impl CustomModification {
+    /// Runs the operation on the given stat, returning false if it already ran
+    pub fn apply(&self, stat: &mut Box<dyn StatData>) -> bool {
+        let Some(operation) = self.take() else {
+            return false;
+        };
+        operation(stat);
+        true
+    }
+
+    /// Takes the operation out so no other clone can run it, returning `None` if it already ran
+    pub(crate) fn take(&self) -> Option<Box<CustomFn>> {
+        self.operation.lock().ok()?.take()
+    }
+
+    /// Returns the stat the operation runs on if the stat doesn't exist, see [`ModificationType::custom_or_default`]
+    pub(crate) fn default_stat(&self) -> Option<Box<dyn StatData>> {
+        self.default.as_ref().map(|default| default.default())
+    }
+}
+
+impl Debug for CustomModification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CustomModification")
+            .field("default", &self.default)
+            .finish_non_exhaustive()
+    }
+}
+
 /// A modification to apply to a stat
+#[derive(Debug, Clone)]
 pub enum ModificationType {
     /// Adds the data contained to the stat
     Add(Box<dyn StatData>),
@@ -12,6 +60,35 @@ pub enum ModificationType {
     Reset,
     /// Sets the stat to the data contained
     Set(Box<dyn StatData>),
+    /// Adds the given percentage of the current value to the stat *IF* it exists, see [`StatData::apply_percent`]
+    AddPercent(f64),
+    /// Subtracts the given percentage of the current value from the stat *IF* it exists, see [`StatData::apply_percent`]
+    SubPercent(f64),
+    /// Sets the stat to the data contained if it is greater than the current value, see [`StatData::compare`].
+    ///
+    /// Creates the stat if it doesn't exist and does nothing if the values can't be compared
+    SetMax(Box<dyn StatData>),
+    /// Sets the stat to the data contained if it is less than the current value, see [`StatData::compare`].
+    ///
+    /// Creates the stat if it doesn't exist and does nothing if the values can't be compared
+    SetMin(Box<dyn StatData>),
+    /// Clamps the stat into the range between `min` and `max` *IF* it exists, see [`StatData::compare`].
+    ///
+    /// Does nothing if `min` is greater than `max` or the values can't be compared
+    Clamp {
+        /// The lowest value the stat is left at
+        min: Box<dyn StatData>,
+        /// The highest value the stat is left at
+        max: Box<dyn StatData>,
+    },
+    /// Flips the stat, creating it as `true` if it doesn't exist, see [`StatData::toggle`]
+    Toggle,
+    /// Runs the contained operation once on the stat, or on a default created stat if one was given with
+    /// [`ModificationType::custom_or_default`]. Otherwise does nothing if the stat doesn't exist.
+    ///
+    /// Closures can't be serialized, so neither can custom modifications or events carrying them. See
+    /// [`CustomModification`] for how clones share the operation
+    Custom(CustomModification),
 }
 
 impl ModificationType {
@@ -35,4 +112,116 @@ impl ModificationType {
     pub fn reset() -> Self {
         Self::Reset
     }
+
+    /// Create a new [`ModificationType::AddPercent`]
+    pub fn add_percent(percent: f64) -> Self {
+        Self::AddPercent(percent)
+    }
+    /// Create a new [`ModificationType::SubPercent`]
+    pub fn sub_percent(percent: f64) -> Self {
+        Self::SubPercent(percent)
+    }
+    /// Create a new [`ModificationType::SetMax`]
+    pub fn set_max(stat_data: impl StatData) -> Self {
+        Self::SetMax(Box::new(stat_data))
+    }
+    /// Create a new [`ModificationType::SetMin`]
+    pub fn set_min(stat_data: impl StatData) -> Self {
+        Self::SetMin(Box::new(stat_data))
+    }
+    /// Create a new [`ModificationType::Clamp`]
+    pub fn clamp(min: impl StatData, max: impl StatData) -> Self {
+        Self::Clamp {
+            min: Box::new(min),
+            max: Box::new(max),
+        }
+    }
+    /// Create a new [`ModificationType::Toggle`]
+    pub fn toggle() -> Self {
+        Self::Toggle
+    }
+    /// Create a new [`ModificationType::Custom`] that runs on the stat *IF* it exists
+    pub fn custom(f: impl FnOnce(&mut Box<dyn StatData>) + Send + Sync + 'static) -> Self {
+        Self::Custom(CustomModification {
+            operation: Arc::new(Mutex::new(Some(Box::new(f)))),
+            default: None,
+        })
+    }
+    /// Create a new [`ModificationType::Custom`] that runs on the stat, creating it from the [`StatData::default`] of
+    /// the given data first if it doesn't exist
+    pub fn custom_or_default(
+        stat_data: impl StatData,
+        f: impl FnOnce(&mut Box<dyn StatData>) + Send + Sync + 'static,
+    ) -> Self {
+        Self::Custom(CustomModification {
+            operation: Arc::new(Mutex::new(Some(Box::new(f)))),
+            default: Some(Box::new(stat_data)),
+        })
+    }
+
+    /// Returns the [`ModificationKind`] of this modification
+    pub fn kind(&self) -> ModificationKind {
+        match self {
+            ModificationType::Add(_) => ModificationKind::Add,
+            ModificationType::Sub(_) => ModificationKind::Sub,
+            ModificationType::Remove => ModificationKind::Remove,
+            ModificationType::Reset => ModificationKind::Reset,
+            ModificationType::Set(_) => ModificationKind::Set,
+            ModificationType::AddPercent(_) => ModificationKind::AddPercent,
+            ModificationType::SubPercent(_) => ModificationKind::SubPercent,
+            ModificationType::SetMax(_) => ModificationKind::SetMax,
+            ModificationType::SetMin(_) => ModificationKind::SetMin,
+            ModificationType::Clamp { .. } => ModificationKind::Clamp,
+            ModificationType::Toggle => ModificationKind::Toggle,
+            ModificationType::Custom(_) => ModificationKind::Custom,
+        }
+    }
+
+    /// Returns the [`ModificationKind`] reported to the observer and in [`StatChanged`](crate::StatChanged) when this
+    /// modification changes a stat.
+    ///
+    /// Differs from [`ModificationType::kind`] where the modification is applied as another kind:
+    /// [`ModificationType::SetMax`], [`ModificationType::SetMin`], and [`ModificationType::Clamp`] replace the stat so
+    /// they report [`ModificationKind::Set`], and a negative percentage reports the opposite percent kind
+    pub fn reported_kind(&self) -> ModificationKind {
+        match self {
+            ModificationType::SetMax(_)
+            | ModificationType::SetMin(_)
+            | ModificationType::Clamp { .. } => ModificationKind::Set,
+            ModificationType::AddPercent(percent) if *percent < 0.0 => ModificationKind::SubPercent,
+            ModificationType::SubPercent(percent) if *percent <= 0.0 => {
+                ModificationKind::AddPercent
+            }
+            modification_type => modification_type.kind(),
+        }
+    }
+}
+
+/// The kind of a [`ModificationType`] without the data it carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModificationKind {
+    /// A [`ModificationType::Add`]
+    Add,
+    /// A [`ModificationType::Sub`]
+    Sub,
+    /// A [`ModificationType::Remove`]
+    Remove,
+    /// A [`ModificationType::Reset`]
+    Reset,
+    /// A [`ModificationType::Set`]
+    Set,
+    /// A [`ModificationType::AddPercent`]
+    AddPercent,
+    /// A [`ModificationType::SubPercent`]
+    SubPercent,
+    /// A [`ModificationType::SetMax`]
+    SetMax,
+    /// A [`ModificationType::SetMin`]
+    SetMin,
+    /// A [`ModificationType::Clamp`]
+    Clamp,
+    /// A [`ModificationType::Toggle`]
+    Toggle,
+    /// A [`ModificationType::Custom`]
+    Custom,
 }
@@ -0,0 +1,127 @@
+//! Loading large json save files one stat at a time instead of building the whole document in memory first.
+
+use std::{
+    fmt::Formatter,
+    io::{BufReader, Read},
+};
+
+use bevy::utils::hashbrown::HashMap;
+use serde::{
+    de::{DeserializeSeed, IgnoredAny, MapAccess, Visitor},
+    Deserializer,
+};
+
+use crate::{StatData, Stats};
+
+impl Stats {
+    /// Deserializes a json [`Stats`] collection from the given reader, such as a save file.
+    ///
+    /// Each stat is read and inserted into the collection as soon as it is parsed, so peak memory stays close to
+    /// the size of the loaded collection rather than the collection plus the whole file. The reader is buffered
+    /// internally.
+    pub fn from_reader(reader: impl Read) -> Result<Stats, serde_json::Error> {
+        let mut stats = Stats::new();
+        let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+        StatsSeed(&mut stats).deserialize(&mut deserializer)?;
+        deserializer.end()?;
+        Ok(stats)
+    }
+}
+
+/// Deserializes the fields of a [`Stats`] directly into an existing collection
+struct StatsSeed<'a>(&'a mut Stats);
+
+impl<'de> DeserializeSeed<'de> for StatsSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StatsSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a stats collection")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        self.0.loaded_version = crate::legacy_stats_version();
+        while let Some(field) = map.next_key::<String>()? {
+            match field.as_str() {
+                "version" => {
+                    let version = map.next_value::<u32>()?;
+                    crate::check_stats_version(version).map_err(serde::de::Error::custom)?;
+                    self.0.loaded_version = Some(version);
+                }
+                "stats" => map.next_value_seed(StatEntriesSeed(&mut self.0.stats))?,
+                "metadata" => self.0.metadata = map.next_value()?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Deserializes stat entries one at a time, inserting each into the map as soon as it is read
+struct StatEntriesSeed<'a>(&'a mut HashMap<String, Box<dyn StatData>>);
+
+impl<'de> DeserializeSeed<'de> for StatEntriesSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for StatEntriesSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a map of stat ids to stat data")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        if let Some(additional) = map.size_hint() {
+            self.0.reserve(additional);
+        }
+        while let Some((stat_id, stat)) = map.next_entry::<String, Box<dyn StatData>>()? {
+            self.0.insert(stat_id, stat);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_utils::Gold, StatMetadata};
+
+    use super::*;
+
+    #[test]
+    fn stats_from_reader() {
+        let mut stats = Stats::new();
+        for index in 0..100u32 {
+            stats.set_stat_manual(&format!("Stat {index}"), Box::new(index));
+        }
+        stats.set_stat(&Gold, Box::new(5u64));
+        stats.set_metadata(&Gold, StatMetadata::new("Gold", "Coins carried"));
+        let json = serde_json::to_vec(&stats).unwrap();
+
+        let loaded = Stats::from_reader(json.as_slice()).unwrap();
+        assert_eq!(loaded.len(), 101);
+        assert_eq!(
+            loaded
+                .get_stat_manual("Stat 42")
+                .and_then(|stat| stat.downcast_ref::<u32>()),
+            Some(&42)
+        );
+        assert_eq!(loaded.get_stat_downcast::<u64>(&Gold), Some(&5));
+        assert_eq!(loaded.get_metadata(&Gold), stats.get_metadata(&Gold));
+
+        assert!(Stats::from_reader(&b"{\"stats\": {\"Gold\": {\"u64\": 5}"[..]).is_err());
+    }
+}
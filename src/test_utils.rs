@@ -0,0 +1,76 @@
+//! Stat identifiers and collections shared by the tests of every module.
+
+use bevy::prelude::{Component, Resource};
+
+use crate::{StatIdentifier, Stats};
+
+macro_rules! impl_test_stat_identifier {
+    ($($ty:ident => $identifier:literal),* $(,)?) => {
+        $(
+            #[derive(Clone, Copy, Hash)]
+            pub struct $ty;
+
+            impl StatIdentifier for $ty {
+                fn identifier(&self) -> &'static str {
+                    $identifier
+                }
+            }
+        )*
+    };
+}
+
+impl_test_stat_identifier!(
+    Attack => "Attack",
+    CurrentWeapon => "Current Weapon",
+    Distance => "Distance",
+    EnemiesKilled => "Enemies Killed",
+    Fps => "Fps",
+    FrameTime => "Frame Time",
+    Gold => "Gold",
+    Health => "Health",
+    Hits => "Hits",
+    ItemsCrafted => "Items Crafted",
+    PlayTime => "Playtime",
+    PlayerGold => "Player Gold",
+    Score => "Score",
+    TurnCounter => "Turn Counter",
+);
+
+/// Implements `AsRef<Stats>` and `AsMut<Stats>` for test collections storing their [`Stats`] in a `stats` field
+macro_rules! impl_test_stat_collection {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl AsMut<$crate::Stats> for $ty {
+                fn as_mut(&mut self) -> &mut $crate::Stats {
+                    &mut self.stats
+                }
+            }
+
+            impl AsRef<$crate::Stats> for $ty {
+                fn as_ref(&self) -> &$crate::Stats {
+                    &self.stats
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_test_stat_collection;
+
+#[derive(Resource, Default)]
+pub struct ResourceStats {
+    pub stats: Stats,
+}
+
+#[derive(Component, Default)]
+pub struct EntityStats {
+    pub stats: Stats,
+}
+
+impl From<Stats> for EntityStats {
+    fn from(stats: Stats) -> Self {
+        EntityStats { stats }
+    }
+}
+
+impl_test_stat_collection!(ResourceStats, EntityStats);
@@ -0,0 +1,289 @@
+//! Events fired when a stat in a resource crosses a registered value.
+
+use std::marker::PhantomData;
+
+use bevy::{
+    app::{App, PostUpdate},
+    prelude::{Event, EventWriter, IntoSystemConfigs, Res, ResMut, Resource},
+};
+
+use crate::{StatData, StatSystemSets, Stats};
+
+/// Which way a stat has to move past a threshold to cross it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThresholdDirection {
+    /// Crossed when the stat goes from below the threshold to at or above it
+    Rising,
+    /// Crossed when the stat goes from above the threshold to at or below it
+    Falling,
+}
+
+/// An event sent when a stat crosses a threshold registered with [`StatAppExt::register_stat_threshold`]
+///
+/// [`StatAppExt::register_stat_threshold`]: crate::StatAppExt::register_stat_threshold
+#[derive(Event)]
+pub struct StatThresholdCrossed<StatCollection, T> {
+    stat_id: String,
+    threshold: T,
+    direction: ThresholdDirection,
+    pd: PhantomData<StatCollection>,
+}
+
+impl<StatCollection, T: Copy> StatThresholdCrossed<StatCollection, T> {
+    /// The id of the stat that crossed the threshold
+    pub fn stat_id(&self) -> &str {
+        &self.stat_id
+    }
+
+    /// The threshold that was crossed
+    pub fn threshold(&self) -> T {
+        self.threshold
+    }
+
+    /// The direction the threshold was crossed in
+    pub fn direction(&self) -> ThresholdDirection {
+        self.direction
+    }
+}
+
+struct Threshold<T> {
+    stat_id: String,
+    value: T,
+    direction: ThresholdDirection,
+}
+
+/// Every threshold registered for a stat collection and value type along with the last seen value of each stat.
+///
+/// The last seen value is `None` until the stat collection has been seen once, so a stat that already starts past a
+/// threshold doesn't cross it
+#[derive(Resource)]
+struct StatThresholds<StatCollection, T> {
+    thresholds: Vec<Threshold<T>>,
+    previous: Vec<Option<T>>,
+    pd: PhantomData<StatCollection>,
+}
+
+/// Returns the value of the given stat, a missing stat or one of a different type counts as the default value
+fn stat_value<T: StatData + Copy + Default>(stats: &Stats, stat_id: &str) -> T {
+    stats
+        .get_stat_manual(stat_id)
+        .and_then(|stat| stat.downcast_ref::<T>())
+        .copied()
+        .unwrap_or_default()
+}
+
+pub(crate) fn register_stat_threshold<
+    StatCollection: AsRef<Stats> + Send + Sync + 'static + Resource,
+    T: StatData + PartialOrd + Copy + Default,
+>(
+    app: &mut App,
+    stat_id: String,
+    value: T,
+    direction: ThresholdDirection,
+) {
+    if !app
+        .world()
+        .contains_resource::<StatThresholds<StatCollection, T>>()
+    {
+        app.add_event::<StatThresholdCrossed<StatCollection, T>>();
+        app.insert_resource(StatThresholds::<StatCollection, T> {
+            thresholds: Vec::new(),
+            previous: Vec::new(),
+            pd: PhantomData,
+        });
+        app.add_systems(
+            PostUpdate,
+            check_stat_thresholds::<StatCollection, T>.after(StatSystemSets::ApplyModifications),
+        );
+    }
+    let previous = app
+        .world()
+        .get_resource::<StatCollection>()
+        .map(|stat_collection| stat_value::<T>(stat_collection.as_ref(), &stat_id));
+    let mut thresholds = app
+        .world_mut()
+        .resource_mut::<StatThresholds<StatCollection, T>>();
+    thresholds.thresholds.push(Threshold {
+        stat_id,
+        value,
+        direction,
+    });
+    thresholds.previous.push(previous);
+}
+
+fn check_stat_thresholds<
+    StatCollection: AsRef<Stats> + Send + Sync + 'static + Resource,
+    T: StatData + PartialOrd + Copy + Default,
+>(
+    stat_collection: Option<Res<StatCollection>>,
+    mut thresholds: ResMut<StatThresholds<StatCollection, T>>,
+    mut crossed_writer: EventWriter<StatThresholdCrossed<StatCollection, T>>,
+) {
+    // Thresholds can be registered before the resource is inserted
+    let Some(stat_collection) = stat_collection else {
+        return;
+    };
+    let stats = stat_collection.as_ref().as_ref();
+    let thresholds = thresholds.as_mut();
+    // Crossings are grouped by stat and direction, in the order each group first crossed a threshold
+    let mut groups: Vec<(&str, ThresholdDirection)> = Vec::new();
+    let mut crossed = Vec::new();
+    for (threshold, previous) in thresholds
+        .thresholds
+        .iter()
+        .zip(thresholds.previous.iter_mut())
+    {
+        let current = stat_value::<T>(stats, &threshold.stat_id);
+        let is_crossed = previous.is_some_and(|previous| match threshold.direction {
+            ThresholdDirection::Rising => previous < threshold.value && current >= threshold.value,
+            ThresholdDirection::Falling => previous > threshold.value && current <= threshold.value,
+        });
+        if is_crossed {
+            let key = (threshold.stat_id.as_str(), threshold.direction);
+            let group = match groups.iter().position(|group| *group == key) {
+                Some(group) => group,
+                None => {
+                    groups.push(key);
+                    groups.len() - 1
+                }
+            };
+            crossed.push((group, threshold));
+        }
+        *previous = Some(current);
+    }
+
+    // A single modification can cross several thresholds of a group, send them in the order they were passed.
+    // Crossed thresholds are never NaN so comparing them within a group is a total order
+    crossed.sort_by(|(a_group, a), (b_group, b)| {
+        a_group.cmp(b_group).then_with(|| {
+            let ordering = a
+                .value
+                .partial_cmp(&b.value)
+                .unwrap_or(std::cmp::Ordering::Equal);
+            match a.direction {
+                ThresholdDirection::Rising => ordering,
+                ThresholdDirection::Falling => ordering.reverse(),
+            }
+        })
+    });
+    for (_, threshold) in crossed {
+        crossed_writer.send(StatThresholdCrossed {
+            stat_id: threshold.stat_id.clone(),
+            threshold: threshold.value,
+            direction: threshold.direction,
+            pd: PhantomData,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{app::App, prelude::Events};
+
+    use crate::{
+        test_utils::{EnemiesKilled, ResourceStats},
+        ModifyStat, StatAppExt,
+    };
+
+    use super::*;
+
+    fn crossed(app: &mut App) -> Vec<(u64, ThresholdDirection)> {
+        app.world_mut()
+            .resource_mut::<Events<StatThresholdCrossed<ResourceStats, u64>>>()
+            .drain()
+            .map(|event| (event.threshold(), event.direction()))
+            .collect()
+    }
+
+    #[test]
+    fn stat_thresholds() {
+        let mut app = App::new();
+        app.register_stat_resource::<ResourceStats>();
+        app.register_stat_threshold::<ResourceStats, u64>(
+            EnemiesKilled,
+            1000,
+            ThresholdDirection::Rising,
+        );
+        app.register_stat_threshold::<ResourceStats, u64>(
+            EnemiesKilled,
+            100,
+            ThresholdDirection::Rising,
+        );
+        app.register_stat_threshold::<ResourceStats, u64>(
+            EnemiesKilled,
+            50,
+            ThresholdDirection::Falling,
+        );
+
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::add(EnemiesKilled, 1500u64));
+        app.update();
+        assert_eq!(
+            crossed(&mut app),
+            vec![
+                (100, ThresholdDirection::Rising),
+                (1000, ThresholdDirection::Rising)
+            ]
+        );
+
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::add(EnemiesKilled, 10u64));
+        app.update();
+        assert_eq!(crossed(&mut app), vec![]);
+
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::set(EnemiesKilled, 20u64));
+        app.update();
+        assert_eq!(crossed(&mut app), vec![(50, ThresholdDirection::Falling)]);
+    }
+
+    #[test]
+    fn thresholds_without_resource() {
+        let mut app = App::new();
+        app.register_stat_threshold::<ResourceStats, u64>(
+            EnemiesKilled,
+            1000,
+            ThresholdDirection::Rising,
+        );
+        app.update();
+        assert_eq!(crossed(&mut app), vec![]);
+    }
+
+    #[test]
+    fn thresholds_start_from_current_value() {
+        let mut app = App::new();
+        app.register_stat_threshold::<ResourceStats, u64>(
+            EnemiesKilled,
+            1000,
+            ThresholdDirection::Rising,
+        );
+        app.register_stat_resource::<ResourceStats>();
+        app.world_mut()
+            .resource_mut::<ResourceStats>()
+            .stats
+            .set_stat(&EnemiesKilled, Box::new(1500u64));
+        app.update();
+        assert_eq!(crossed(&mut app), vec![]);
+
+        app.register_stat_threshold::<ResourceStats, u64>(
+            EnemiesKilled,
+            1200,
+            ThresholdDirection::Rising,
+        );
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::set(EnemiesKilled, 0u64));
+        app.update();
+        assert_eq!(crossed(&mut app), vec![]);
+
+        app.world_mut()
+            .send_event(ModifyStat::<ResourceStats>::add(EnemiesKilled, 2000u64));
+        app.update();
+        assert_eq!(
+            crossed(&mut app),
+            vec![
+                (1000, ThresholdDirection::Rising),
+                (1200, ThresholdDirection::Rising)
+            ]
+        );
+    }
+}
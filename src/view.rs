@@ -0,0 +1,215 @@
+//! Views over the stats under a single id prefix so a subsystem can use short ids.
+
+use crate::{stat_modification::ModificationType, StatData, Stats};
+
+/// A read only view over every stat whose id starts with a prefix, created by [`Stats::scope`].
+///
+/// Ids passed to and returned from the view are relative to the prefix, a `scope("ui/")` view's `get("health")`
+/// reads `"ui/health"`. The view borrows the [`Stats`] and never copies stat data
+#[derive(Clone, Copy)]
+pub struct StatsView<'a> {
+    stats: &'a Stats,
+    prefix: &'a str,
+}
+
+impl<'a> StatsView<'a> {
+    /// Returns the prefix prepended to every id
+    pub fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    /// Gets the [`StatData`] under the given id relative to the prefix
+    #[allow(clippy::borrowed_box)]
+    pub fn get(&self, stat_id: &str) -> Option<&'a Box<dyn StatData>> {
+        self.stats.get_stat_manual(&scoped_id(self.prefix, stat_id))
+    }
+
+    /// Gets the [`StatData`] under the given id relative to the prefix and attempts to downcast it into the given type
+    pub fn get_downcast<Stat: StatData + 'static>(&self, stat_id: &str) -> Option<&'a Stat> {
+        self.get(stat_id)?.downcast_ref::<Stat>()
+    }
+
+    /// Returns true if a stat exists under the given id relative to the prefix
+    pub fn contains(&self, stat_id: &str) -> bool {
+        self.stats
+            .contains_stat_manual(&scoped_id(self.prefix, stat_id))
+    }
+
+    /// Returns an iterator over every stat in the view with its id relative to the prefix
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a Box<dyn StatData>)> {
+        let prefix = self.prefix;
+        self.stats
+            .iter_prefix(prefix)
+            .map(move |(stat_id, stat)| (&stat_id[prefix.len()..], stat))
+    }
+
+    /// Returns an iterator over every stat id in the view relative to the prefix
+    pub fn ids(&self) -> impl Iterator<Item = &'a str> {
+        self.iter().map(|(stat_id, _)| stat_id)
+    }
+}
+
+/// A mutable view over every stat whose id starts with a prefix, created by [`Stats::scope_mut`].
+///
+/// See [`StatsView`]. Stats created through the view are stored under the prefixed id
+pub struct StatsViewMut<'a> {
+    stats: &'a mut Stats,
+    prefix: &'a str,
+}
+
+impl StatsViewMut<'_> {
+    /// Returns the prefix prepended to every id
+    pub fn prefix(&self) -> &str {
+        self.prefix
+    }
+
+    /// Returns a read only [`StatsView`] with the same prefix
+    pub fn as_view(&self) -> StatsView<'_> {
+        StatsView {
+            stats: self.stats,
+            prefix: self.prefix,
+        }
+    }
+
+    /// Gets the [`StatData`] under the given id relative to the prefix
+    #[allow(clippy::borrowed_box)]
+    pub fn get(&self, stat_id: &str) -> Option<&Box<dyn StatData>> {
+        self.stats.get_stat_manual(&scoped_id(self.prefix, stat_id))
+    }
+
+    /// Gets the [`StatData`] under the given id relative to the prefix and attempts to downcast it into the given type
+    pub fn get_downcast<Stat: StatData + 'static>(&self, stat_id: &str) -> Option<&Stat> {
+        self.get(stat_id)?.downcast_ref::<Stat>()
+    }
+
+    /// Downcasts the [`StatData`] under the given id relative to the prefix and runs the given function on it, see
+    /// [`Stats::map_stat_downcast_manual`]
+    pub fn map_downcast<Stat: StatData + 'static>(
+        &mut self,
+        stat_id: &str,
+        f: impl FnOnce(&mut Stat),
+    ) -> bool {
+        self.stats
+            .map_stat_downcast_manual(&scoped_id(self.prefix, stat_id), f)
+    }
+
+    /// Returns true if a stat exists under the given id relative to the prefix
+    pub fn contains(&self, stat_id: &str) -> bool {
+        self.stats
+            .contains_stat_manual(&scoped_id(self.prefix, stat_id))
+    }
+
+    /// Adds the given [`StatData`] to the given id relative to the prefix, see [`Stats::add_to_stat_manual`]
+    pub fn add(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
+        self.stats
+            .add_to_stat_manual(&scoped_id(self.prefix, stat_id), stat_data);
+    }
+
+    /// Subs the given [`StatData`] from the given id relative to the prefix, see [`Stats::sub_from_stat_manual`]
+    pub fn sub(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
+        self.stats
+            .sub_from_stat_manual(&scoped_id(self.prefix, stat_id), stat_data);
+    }
+
+    /// Sets the given [`StatData`] under the given id relative to the prefix, see [`Stats::set_stat_manual`]
+    pub fn set(&mut self, stat_id: &str, stat_data: Box<dyn StatData>) {
+        self.stats
+            .set_stat_manual(&scoped_id(self.prefix, stat_id), stat_data);
+    }
+
+    /// Removes the stat under the given id relative to the prefix, returning true if it existed, see
+    /// [`Stats::remove_stat_manual`]
+    pub fn remove(&mut self, stat_id: &str) -> bool {
+        self.stats
+            .remove_stat_manual(&scoped_id(self.prefix, stat_id))
+    }
+
+    /// Resets the stat under the given id relative to the prefix, see [`Stats::reset_stat_manual`]
+    pub fn reset(&mut self, stat_id: &str) {
+        self.stats
+            .reset_stat_manual(&scoped_id(self.prefix, stat_id));
+    }
+
+    /// Resets every stat in the view
+    pub fn reset_all(&mut self) {
+        let prefix = self.prefix;
+        self.stats
+            .reset_matching(|stat_id| stat_id.starts_with(prefix));
+    }
+
+    /// Applies the given [`ModificationType`] to the given id relative to the prefix, see
+    /// [`Stats::apply_modification_manual`]
+    pub fn apply_modification(&mut self, stat_id: &str, modification_type: ModificationType) {
+        self.stats
+            .apply_modification_manual(&scoped_id(self.prefix, stat_id), modification_type);
+    }
+
+    /// Returns an iterator over every stat in the view with its id relative to the prefix
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Box<dyn StatData>)> {
+        let prefix = self.prefix;
+        self.stats
+            .iter_prefix(prefix)
+            .map(move |(stat_id, stat)| (&stat_id[prefix.len()..], stat))
+    }
+
+    /// Returns an iterator over every stat id in the view relative to the prefix
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(stat_id, _)| stat_id)
+    }
+}
+
+fn scoped_id(prefix: &str, stat_id: &str) -> String {
+    format!("{prefix}{stat_id}")
+}
+
+impl Stats {
+    /// Returns a read only [`StatsView`] over every stat whose id starts with the given prefix.
+    ///
+    /// Like [`Stats::iter_prefix`] the separator is only a convention, include it in the prefix such as `"ui/"`
+    pub fn scope<'a>(&'a self, prefix: &'a str) -> StatsView<'a> {
+        StatsView {
+            stats: self,
+            prefix,
+        }
+    }
+
+    /// Returns a [`StatsViewMut`] over every stat whose id starts with the given prefix, see [`Stats::scope`]
+    pub fn scope_mut<'a>(&'a mut self, prefix: &'a str) -> StatsViewMut<'a> {
+        StatsViewMut {
+            stats: self,
+            prefix,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scoped_views() {
+        let mut stats = Stats::new();
+        stats.set_stat_manual("ui/health", Box::new(10u32));
+        stats.set_stat_manual("combat/health", Box::new(50u32));
+
+        let mut ui = stats.scope_mut("ui/");
+        ui.add("health", Box::new(5u32));
+        ui.set("clicks", Box::new(3u64));
+        assert!(ui.contains("clicks"));
+        assert!(!ui.contains("ui/clicks"));
+        assert!(ui.map_downcast::<u64>("clicks", |clicks| *clicks += 1));
+
+        let ui = stats.scope("ui/");
+        assert_eq!(ui.get_downcast::<u32>("health"), Some(&15));
+        let mut ids = ui.ids().collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert_eq!(ids, vec!["clicks", "health"]);
+
+        stats.scope_mut("ui/").reset_all();
+        assert_eq!(stats.scope("ui/").get_downcast::<u64>("clicks"), Some(&0));
+        assert_eq!(
+            stats.scope("combat/").get_downcast::<u32>("health"),
+            Some(&50)
+        );
+    }
+}